@@ -119,3 +119,39 @@ pub mod iec {
     /** 2<sup>80</sup> */
     pub type Yobi<Bits> = Fix<Bits, U2, P80>;
 }
+
+/// Base-10 types with the exponent in the name, such as `UFix64N9` for `decimal::UFix64<N9>`,
+/// from 10<sup>-18</sup> to 10<sup>18</sup>.
+///
+/// Anchor's IDL generator cannot read the typenum arguments of `decimal` types, so account and
+/// instruction fields built with the `idl-build` feature must use these. They appear in the IDL
+/// under the same names.
+pub mod named {
+    use paste::paste;
+
+    use super::decimal;
+
+    macro_rules! named {
+        ($($exp:ident),+) => {
+            paste! {
+                $(
+                    pub type [<UFix8 $exp>] = decimal::UFix8<typenum::$exp>;
+                    pub type [<UFix16 $exp>] = decimal::UFix16<typenum::$exp>;
+                    pub type [<UFix32 $exp>] = decimal::UFix32<typenum::$exp>;
+                    pub type [<UFix64 $exp>] = decimal::UFix64<typenum::$exp>;
+                    pub type [<UFixSize $exp>] = decimal::UFixSize<typenum::$exp>;
+                    pub type [<UFix128 $exp>] = decimal::UFix128<typenum::$exp>;
+                    pub type [<IFix8 $exp>] = decimal::IFix8<typenum::$exp>;
+                    pub type [<IFix16 $exp>] = decimal::IFix16<typenum::$exp>;
+                    pub type [<IFix32 $exp>] = decimal::IFix32<typenum::$exp>;
+                    pub type [<IFix64 $exp>] = decimal::IFix64<typenum::$exp>;
+                    pub type [<IFixSize $exp>] = decimal::IFixSize<typenum::$exp>;
+                    pub type [<IFix128 $exp>] = decimal::IFix128<typenum::$exp>;
+                )+
+            }
+        };
+    }
+
+    named!(N18, N17, N16, N15, N14, N13, N12, N11, N10, N9, N8, N7, N6, N5, N4, N3, N2, N1, Z0);
+    named!(P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13, P14, P15, P16, P17, P18);
+}
@@ -0,0 +1,136 @@
+//! Borsh serialization of `Fix`.
+//!
+//! `Fix` is encoded exactly as its _Bits_, with _Base_ and _Exp_ fixed by the type. Account fields
+//! such as `UFix64<N9>` are therefore as compact as the bare integer and load infallibly. Use
+//! `FixValue` instead where the exponent must travel with the value.
+//!
+//! [`Tagged`] is a self-describing alternative which also writes _Exp_ and _Base_.
+//!
+//! [`Tagged`]: crate::tagged::Tagged
+
+use borsh::maybestd::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::Fix;
+
+impl<Bits, Base, Exp> BorshSerialize for Fix<Bits, Base, Exp>
+where
+    Bits: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.bits.serialize(writer)
+    }
+}
+
+impl<Bits, Base, Exp> BorshDeserialize for Fix<Bits, Base, Exp>
+where
+    Bits: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Bits::deserialize_reader(reader).map(Self::new)
+    }
+}
+
+#[cfg(feature = "anchor")]
+mod space {
+    use anchor_lang::Space;
+
+    use crate::Fix;
+
+    macro_rules! impl_space {
+        ($bits:ident, $space:expr) => {
+            impl<Base, Exp> Space for Fix<$bits, Base, Exp> {
+                const INIT_SPACE: usize = $space;
+            }
+        };
+    }
+
+    impl_space!(u8, 1);
+    impl_space!(u16, 2);
+    impl_space!(u32, 4);
+    impl_space!(u64, 8);
+    impl_space!(u128, 16);
+    // Borsh encodes `usize` and `isize` as 64-bit.
+    impl_space!(usize, 8);
+    impl_space!(i8, 1);
+    impl_space!(i16, 2);
+    impl_space!(i32, 4);
+    impl_space!(i64, 8);
+    impl_space!(i128, 16);
+    impl_space!(isize, 8);
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::{to_vec, BorshDeserialize};
+
+    use crate::aliases::binary;
+    use crate::aliases::decimal::{IFix128, IFix8, UFix64};
+    use crate::typenum::{N18, N3, N9};
+
+    #[test]
+    fn encodes_bits_only() {
+        let price = UFix64::<N9>::new(1_500_000_000);
+        assert_eq!(to_vec(&price).unwrap(), to_vec(&price.bits).unwrap());
+        assert_eq!(to_vec(&IFix8::<N3>::new(-1)).unwrap(), [0xff]);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let value = IFix128::<N18>::new(-123_456_789_012_345_678_901);
+        let bytes = to_vec(&value).unwrap();
+        assert_eq!(IFix128::<N18>::try_from_slice(&bytes).unwrap(), value);
+
+        let value = binary::UFix64::<N9>::new(u64::MAX);
+        let bytes = to_vec(&value).unwrap();
+        assert_eq!(binary::UFix64::<N9>::try_from_slice(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn short_input_fails() {
+        assert!(UFix64::<N9>::try_from_slice(&[0; 7]).is_err());
+    }
+
+    #[cfg(feature = "anchor")]
+    #[test]
+    fn space_matches_encoding() {
+        use anchor_lang::Space;
+
+        use crate::aliases::decimal::{IFixSize, UFix128};
+
+        assert_eq!(UFix64::<N9>::INIT_SPACE, 8);
+        assert_eq!(IFix8::<N3>::INIT_SPACE, 1);
+        assert_eq!(
+            UFix128::<N18>::INIT_SPACE,
+            to_vec(&UFix128::<N18>::new(0)).unwrap().len()
+        );
+        assert_eq!(
+            IFixSize::<N3>::INIT_SPACE,
+            to_vec(&IFixSize::<N3>::new(0)).unwrap().len()
+        );
+    }
+
+    #[cfg(feature = "anchor")]
+    #[test]
+    fn account_fields() {
+        use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize, InitSpace};
+        use anchor_lang::Space;
+
+        use crate::aliases::named::{IFix8N3, UFix64N9};
+
+        // Named aliases, which the IDL generator of the `idl-build` feature can read.
+        #[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Debug, PartialEq)]
+        struct Pool {
+            price: UFix64N9,
+            fee: IFix8N3,
+        }
+
+        let pool = Pool {
+            price: UFix64::new(2_000_000_000),
+            fee: IFix8::new(-5),
+        };
+        let bytes = to_vec(&pool).unwrap();
+        assert_eq!(bytes.len(), Pool::INIT_SPACE);
+        assert_eq!(Pool::try_from_slice(&bytes).unwrap(), pool);
+    }
+}
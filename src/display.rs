@@ -0,0 +1,334 @@
+use core::fmt::{self, Alignment, Display, Formatter, Write};
+use core::iter;
+
+use crate::rounding::{Discarded, Rounding};
+use crate::typenum::{Integer, U10, U2};
+use crate::Fix;
+
+/// Decimal digits of _|bits| × 10<sup>zeros</sup> ÷ factor<sup>count</sup>_, which must divide
+/// exactly. They are regenerated from `bits` on every pass rather than buffered, so their number
+/// is unbounded.
+#[derive(Clone, Copy)]
+struct Expansion<'a, Bits> {
+    bits: &'a Bits,
+    zeros: u32,
+    factor: u128,
+    count: u32,
+}
+
+impl<'a, Bits: Display> Expansion<'a, Bits> {
+    fn new(bits: &'a Bits) -> Self {
+        Self::scaled(bits, 0, 1, 0)
+    }
+
+    fn scaled(bits: &'a Bits, zeros: u32, factor: u128, count: u32) -> Self {
+        Expansion {
+            bits,
+            zeros,
+            factor,
+            count,
+        }
+    }
+
+    /// Pushes every digit to `sink`, most significant first and leading zeros included, and
+    /// returns whether the value is negative.
+    fn replay(&self, sink: &mut dyn FnMut(u8)) -> Result<bool, fmt::Error> {
+        self.divide(sink, self.count)
+    }
+
+    /// Divides by _factor<sup>count</sup>_ with a chain of long divisions, one per stack frame.
+    fn divide(&self, sink: &mut dyn FnMut(u8), count: u32) -> Result<bool, fmt::Error> {
+        if count == 0 {
+            let mut feed = Feed {
+                sink,
+                negative: false,
+            };
+            write!(feed, "{}", self.bits)?;
+            (0..self.zeros).for_each(|_| (feed.sink)(0));
+            return Ok(feed.negative);
+        }
+        // Largest power of `factor` that keeps `10 × divisor` within `u128`.
+        let step = count.min((u128::MAX / 10).ilog(self.factor));
+        let divisor = self.factor.pow(step);
+        let mut remainder = 0;
+        let mut stage = |digit| {
+            let dividend = remainder * 10 + u128::from(digit);
+            remainder = dividend % divisor;
+            // A single digit, as the remainder carried in is below the divisor.
+            #[allow(clippy::cast_possible_truncation)]
+            sink((dividend / divisor) as u8);
+        };
+        self.divide(&mut stage, count - step)
+    }
+}
+
+/// Passes the digits of an integer's `Display` output to a sink, noting its sign.
+struct Feed<'a> {
+    sink: &'a mut dyn FnMut(u8),
+    negative: bool,
+}
+
+impl Write for Feed<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for byte in s.bytes() {
+            match byte {
+                b'-' => self.negative = true,
+                b'0'..=b'9' => (self.sink)(byte - b'0'),
+                _ => return Err(fmt::Error),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The digits of an [`Expansion`] without leading zeros: the first `copied` of them, then `carry`
+/// and `zeros` zeros in place of any dropped by rounding.
+struct Digits<'a, Bits> {
+    expansion: Expansion<'a, Bits>,
+    skip: usize,
+    copied: usize,
+    carry: Option<u8>,
+    zeros: usize,
+}
+
+impl<'a, Bits: Display> Digits<'a, Bits> {
+    /// Measures an expansion, returning its digits and whether it is negative.
+    fn new(expansion: Expansion<'a, Bits>) -> Result<(Self, bool), fmt::Error> {
+        let (mut len, mut skip) = (0_usize, 0);
+        let negative = expansion.replay(&mut |digit| {
+            if digit == 0 && skip == len {
+                skip += 1;
+            }
+            len += 1;
+        })?;
+        // Zero keeps a single digit.
+        let skip = skip.min(len.saturating_sub(1));
+        let digits = Digits {
+            expansion,
+            skip,
+            copied: len - skip,
+            carry: None,
+            zeros: 0,
+        };
+        Ok((digits, negative))
+    }
+
+    fn len(&self) -> usize {
+        self.copied + usize::from(self.carry.is_some()) + self.zeros
+    }
+
+    fn replay(&self, sink: &mut dyn FnMut(u8)) -> fmt::Result {
+        let copied = self.skip..self.skip + self.copied;
+        let mut index = 0;
+        self.expansion.replay(&mut |digit| {
+            if copied.contains(&index) {
+                sink(digit);
+            }
+            index += 1;
+        })?;
+        let rest = self.carry.into_iter().chain(iter::repeat_n(0, self.zeros));
+        rest.for_each(sink);
+        Ok(())
+    }
+
+    /// Drops the last `count` digits, rounding what is kept.
+    fn round(self, count: usize, negative: bool, rounding: Rounding) -> Result<Self, fmt::Error> {
+        let kept = self.copied.saturating_sub(count);
+        let (mut last_non_nine, mut odd, mut first, mut sticky) = (None, false, 0, false);
+        let mut index = 0;
+        // Digits dropped beyond the expansion are implicit leading zeros of the discarded part.
+        let first_index = (count <= self.copied).then_some(kept);
+        self.replay(&mut |digit| {
+            if index < kept {
+                if digit != 9 {
+                    last_non_nine = Some((index, digit));
+                }
+                odd = digit % 2 == 1;
+            } else if Some(index) == first_index {
+                first = digit;
+            } else {
+                sticky |= digit != 0;
+            }
+            index += 1;
+        })?;
+        // A nonzero digit stands in for the sticky rest.
+        let discarded = Discarded::of_digits([first, u8::from(sticky)].into_iter());
+        let (copied, carry, zeros) = match last_non_nine {
+            _ if !rounding.bumps(negative, kept > 0 && odd, discarded) => {
+                (kept, (kept == 0).then_some(0), 0)
+            }
+            Some((index, digit)) => (index, Some(digit + 1), kept - index - 1),
+            // All nines carry into a new leading digit.
+            None => (0, Some(1), kept),
+        };
+        Ok(Digits {
+            copied,
+            carry,
+            zeros,
+            ..self
+        })
+    }
+}
+
+/// Writes an expansion scaled by _10<sup>exp</sup>_, rounding to the precision of `f` if it is
+/// shorter.
+fn fmt_expansion<Bits: Display>(
+    f: &mut Formatter,
+    expansion: Expansion<'_, Bits>,
+    exp: i32,
+    rounding: Rounding,
+) -> fmt::Result {
+    let scale = usize::try_from(exp.min(0).unsigned_abs()).map_err(|_| fmt::Error)?;
+    let (digits, negative) = Digits::new(expansion)?;
+    match f.precision() {
+        None => write_decimal(f, negative, &digits, exp, scale),
+        Some(decimals) if decimals >= scale => write_decimal(f, negative, &digits, exp, decimals),
+        Some(decimals) => {
+            let digits = digits.round(scale - decimals, negative, rounding)?;
+            let exp = -i32::try_from(decimals).map_err(|_| fmt::Error)?;
+            write_decimal(f, negative, &digits, exp, decimals)
+        }
+    }
+}
+
+fn repeat(f: &mut Formatter, c: char, count: usize) -> fmt::Result {
+    (0..count).try_for_each(|_| f.write_char(c))
+}
+
+/// Writes _digits × 10<sup>exp</sup>_ with `decimals` fractional digits, honoring the width,
+/// fill, alignment and sign flags of `f`. `decimals` must be at least `-exp`.
+fn write_decimal<Bits: Display>(
+    f: &mut Formatter,
+    negative: bool,
+    digits: &Digits<'_, Bits>,
+    exp: i32,
+    decimals: usize,
+) -> fmt::Result {
+    let scale = usize::try_from(exp.unsigned_abs()).map_err(|_| fmt::Error)?;
+    let len = digits.len();
+    // Integer digits, zeros after them and zeros leading the fraction.
+    let (integer, zeros, leading) = match exp {
+        0.. => (len, scale, 0),
+        _ if len > scale => (len - scale, 0, 0),
+        _ => (0, 0, scale - len),
+    };
+    let fraction = len - integer;
+    let trailing = decimals.checked_sub(leading + fraction).ok_or(fmt::Error)?;
+
+    let sign = match (negative, f.sign_plus()) {
+        (true, _) => "-",
+        (false, true) => "+",
+        (false, false) => "",
+    };
+    let point = if decimals > 0 { 1 + decimals } else { 0 };
+    let len = sign.len() + integer.max(1) + zeros + point;
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    let fill = f.fill();
+    let (before, after) = match f.align() {
+        _ if f.sign_aware_zero_pad() => (0, 0),
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+
+    repeat(f, fill, before)?;
+    f.write_str(sign)?;
+    if f.sign_aware_zero_pad() {
+        repeat(f, '0', padding)?;
+    }
+    if integer == 0 {
+        f.write_char('0')?;
+    }
+    let mut written = Ok(());
+    let mut index = 0;
+    digits.replay(&mut |digit| {
+        if index == integer && fraction > 0 {
+            written = written
+                .and_then(|()| f.write_char('.'))
+                .and_then(|()| repeat(f, '0', leading));
+        }
+        written = written.and_then(|()| f.write_char(char::from(b'0' + digit)));
+        index += 1;
+    })?;
+    written?;
+    repeat(f, '0', zeros)?;
+    if decimals > 0 && fraction == 0 {
+        f.write_char('.')?;
+    }
+    repeat(f, '0', trailing)?;
+    repeat(f, fill, after)
+}
+
+/// Writes _bits × 10<sup>exp</sup>_ like a decimal `Fix`, for an _Exp_ known only at runtime.
+pub(crate) fn fmt_decimal<Bits: Display>(
+    f: &mut Formatter,
+    bits: &Bits,
+    exp: i32,
+    rounding: Rounding,
+) -> fmt::Result {
+    fmt_expansion(f, Expansion::new(bits), exp, rounding)
+}
+
+/// Formats a `Fix`, rounding to the `{:.N}` precision with a chosen mode.
+///
+/// Created by [`Fix::display_with_rounding`].
+pub struct DisplayWithRounding<'a, Bits, Base, Exp> {
+    fix: &'a Fix<Bits, Base, Exp>,
+    rounding: Rounding,
+}
+
+impl<Bits, Base, Exp> Fix<Bits, Base, Exp> {
+    /// Formats with `Display`, rounding with `rounding` when the `{:.N}` precision is shorter than
+    /// the exact expansion. Plain `Display` rounds decimal numbers with [`Rounding::HalfEven`],
+    /// like primitive floats, and truncates binary numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let x = UFix64::<N3>::new(1_235);
+    /// assert_eq!(format!("{x:.2}"), "1.24");
+    /// assert_eq!(format!("{:.2}", x.display_with_rounding(Rounding::TowardZero)), "1.23");
+    /// assert_eq!(format!("{x:.5}"), "1.23500");
+    /// ```
+    pub fn display_with_rounding(
+        &self,
+        rounding: Rounding,
+    ) -> DisplayWithRounding<'_, Bits, Base, Exp> {
+        DisplayWithRounding {
+            fix: self,
+            rounding,
+        }
+    }
+}
+
+impl<Bits, Exp> Display for DisplayWithRounding<'_, Bits, U10, Exp>
+where
+    Bits: Display,
+    Exp: Integer,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_decimal(f, &self.fix.bits, Exp::to_i32(), self.rounding)
+    }
+}
+
+/// Every binary fraction terminates in decimal: _x × 2<sup>−k</sup> = x × 10<sup>k</sup> ÷
+/// 2<sup>k</sup> × 10<sup>−k</sup>_, and likewise _x × 2<sup>k</sup> = x × 10<sup>k</sup> ÷
+/// 5<sup>k</sup>_.
+impl<Bits, Exp> Display for DisplayWithRounding<'_, Bits, U2, Exp>
+where
+    Bits: Display,
+    Exp: Integer,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let exp = Exp::to_i32();
+        let bits = &self.fix.bits;
+        if exp < 0 {
+            let expansion = Expansion::scaled(bits, exp.unsigned_abs(), 2, exp.unsigned_abs());
+            fmt_expansion(f, expansion, exp, self.rounding)
+        } else {
+            let expansion = Expansion::scaled(bits, exp.unsigned_abs(), 5, exp.unsigned_abs());
+            fmt_expansion(f, expansion, 0, self.rounding)
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "anchor")]
+use anchor_lang::error::Error as AnchorError;
+#[cfg(feature = "anchor")]
+use anchor_lang::error_code;
+
+use crate::fix_value::ExponentMismatch;
+
+/// Failure of a fallible `Fix` operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FixError {
+    /// The result is above the maximum of _Bits_.
+    Overflow,
+    /// The result is below the minimum of _Bits_.
+    Underflow,
+    /// The divisor is zero.
+    DivisionByZero,
+    /// A `FixValue` does not have the exponent of the `Fix` it is converted into.
+    ExponentMismatch { expected: i8, actual: i8 },
+    /// The result is not exactly representable at the target precision.
+    PrecisionLoss,
+    /// A negative value was converted to unsigned _Bits_.
+    NegativeToUnsigned,
+}
+
+impl FixError {
+    /// The out of range error for a result of the given sign.
+    pub(crate) fn out_of_range(negative: bool) -> Self {
+        if negative {
+            Self::Underflow
+        } else {
+            Self::Overflow
+        }
+    }
+}
+
+impl Display for FixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("Fixed-point overflow."),
+            Self::Underflow => f.write_str("Fixed-point underflow."),
+            Self::DivisionByZero => f.write_str("Fixed-point division by zero."),
+            Self::ExponentMismatch { expected, actual } => write!(
+                f,
+                "Exponent mismatch converting `FixValue` to `Fix`: expected: {expected}, got: {actual}."
+            ),
+            Self::PrecisionLoss => f.write_str("Fixed-point precision loss."),
+            Self::NegativeToUnsigned => f.write_str("Negative fixed-point value to unsigned."),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+impl std::error::Error for FixError {}
+
+impl From<ExponentMismatch> for FixError {
+    fn from(ExponentMismatch { expected, actual }: ExponentMismatch) -> Self {
+        Self::ExponentMismatch { expected, actual }
+    }
+}
+
+/// Anchor error codes reserved for [`FixErrorCode`].
+///
+/// Codes are stable: each variant keeps its number, and new ones are appended within this range.
+/// Programs using this crate should keep their own `#[error_code]` numbers out of it, which the
+/// default offset of 6000 does for up to 1000 codes.
+#[cfg(feature = "anchor")]
+pub const FIX_ERROR_CODES: core::ops::Range<u32> = 7000..7100;
+
+/// Anchor error codes of fixed-point failures, numbered from the start of [`FIX_ERROR_CODES`].
+///
+/// [`FixError`] converts to these, as do `Option` results with [`fix_ok!`]. An exponent
+/// mismatch converted from [`FixError`] or [`ExponentMismatch`] logs the expected and actual
+/// exponents as compared values.
+///
+/// [`fix_ok!`]: crate::fix_ok
+#[cfg(feature = "anchor")]
+#[error_code(offset = 7000)]
+pub enum FixErrorCode {
+    /// 7000
+    #[msg("Fixed-point overflow")]
+    Overflow,
+    /// 7001
+    #[msg("Fixed-point underflow")]
+    Underflow,
+    /// 7002
+    #[msg("Fixed-point division by zero")]
+    DivisionByZero,
+    /// 7003
+    #[msg("Fixed-point exponent mismatch")]
+    ExponentMismatch,
+    /// 7004
+    #[msg("Fixed-point precision loss")]
+    PrecisionLoss,
+    /// 7005
+    #[msg("Negative fixed-point value to unsigned")]
+    NegativeToUnsigned,
+    /// 7006
+    #[msg("Fixed-point value does not fit narrower bits")]
+    NarrowingFailure,
+}
+
+#[cfg(feature = "anchor")]
+impl From<FixError> for FixErrorCode {
+    fn from(error: FixError) -> Self {
+        match error {
+            FixError::Overflow => Self::Overflow,
+            FixError::Underflow => Self::Underflow,
+            FixError::DivisionByZero => Self::DivisionByZero,
+            FixError::ExponentMismatch { .. } => Self::ExponentMismatch,
+            FixError::PrecisionLoss => Self::PrecisionLoss,
+            FixError::NegativeToUnsigned => Self::NegativeToUnsigned,
+        }
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl From<FixError> for AnchorError {
+    fn from(error: FixError) -> AnchorError {
+        let anchor_error = AnchorError::from(FixErrorCode::from(error));
+        match error {
+            FixError::ExponentMismatch { expected, actual } => {
+                anchor_error.with_values((expected, actual))
+            }
+            _ => anchor_error,
+        }
+    }
+}
+
+/// Converts an `Option` result into a `Result` with a [`FixErrorCode`], ready for `?` in an
+/// Anchor instruction.
+///
+/// # Examples
+///
+/// ```
+/// use fix::prelude::*;
+/// fn double(x: UFix64<N3>) -> anchor_lang::Result<UFix64<N3>> {
+///     Ok(fix::fix_ok!(x.mul_floor(UFix64::new(2_000)), Overflow)?)
+/// }
+/// assert!(double(UFix64::new(1_500)).is_ok());
+/// assert!(double(UFix64::new(u64::MAX)).is_err());
+/// ```
+#[cfg(feature = "anchor")]
+#[macro_export]
+macro_rules! fix_ok {
+    ($option:expr, $code:ident) => {
+        $option.ok_or($crate::error::FixErrorCode::$code)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FixError;
+    use crate::fix_value::ExponentMismatch;
+
+    #[test]
+    fn from_exponent_mismatch() {
+        let mismatch = ExponentMismatch {
+            expected: 3,
+            actual: -11,
+        };
+        assert_eq!(
+            FixError::from(mismatch),
+            FixError::ExponentMismatch {
+                expected: 3,
+                actual: -11
+            }
+        );
+    }
+
+    #[test]
+    fn out_of_range_follows_sign() {
+        assert_eq!(FixError::out_of_range(false), FixError::Overflow);
+        assert_eq!(FixError::out_of_range(true), FixError::Underflow);
+    }
+
+    #[cfg(feature = "anchor")]
+    #[test]
+    fn anchor_codes_are_distinct() {
+        use anchor_lang::error::Error as AnchorError;
+
+        let errors = [
+            FixError::Overflow,
+            FixError::Underflow,
+            FixError::DivisionByZero,
+            FixError::ExponentMismatch {
+                expected: 0,
+                actual: 1,
+            },
+            FixError::PrecisionLoss,
+            FixError::NegativeToUnsigned,
+        ];
+        let codes: Vec<u32> = errors
+            .into_iter()
+            .map(|error| match AnchorError::from(error) {
+                AnchorError::AnchorError(error) => error.error_code_number,
+                AnchorError::ProgramError(_) => unreachable!(),
+            })
+            .collect();
+        assert_eq!(codes, [7000, 7001, 7002, 7003, 7004, 7005]);
+    }
+
+    #[cfg(feature = "anchor")]
+    #[test]
+    fn exponent_mismatch_logs_exponents() {
+        use anchor_lang::error::{ComparedValues, Error as AnchorError};
+
+        let mismatch = ExponentMismatch {
+            expected: -9,
+            actual: -6,
+        };
+        let AnchorError::AnchorError(error) = AnchorError::from(mismatch) else {
+            unreachable!();
+        };
+        assert_eq!(error.error_code_number, 7003);
+        assert!(matches!(
+            error.compared_values,
+            Some(ComparedValues::Values((expected, actual))) if expected == "-9" && actual == "-6"
+        ));
+    }
+
+    #[cfg(feature = "anchor")]
+    #[test]
+    fn codes_within_reserved_range() {
+        use super::{FixErrorCode, FIX_ERROR_CODES};
+
+        for code in [FixErrorCode::Overflow, FixErrorCode::NarrowingFailure] {
+            assert!(FIX_ERROR_CODES.contains(&u32::from(code)));
+        }
+    }
+
+    #[cfg(feature = "anchor")]
+    #[test]
+    fn fix_ok_maps_none() {
+        use super::FixErrorCode;
+
+        let narrowed = crate::fix_ok!(300u16.try_into().ok(), NarrowingFailure);
+        assert!(matches!(
+            narrowed,
+            Err::<u8, _>(FixErrorCode::NarrowingFailure)
+        ));
+        assert!(matches!(crate::fix_ok!(Some(1), Overflow), Ok(1)));
+    }
+}
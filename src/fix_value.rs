@@ -1,13 +1,28 @@
+use core::cmp::Ordering;
 use core::fmt::{self, Display, Formatter};
-use std::error::Error;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
+#[cfg(feature = "anchor")]
 use anchor_lang::error::Error as AnchorError;
-use anchor_lang::error::ErrorCode::InvalidNumericConversion;
-use anchor_lang::prelude::{borsh, AnchorDeserialize, AnchorSerialize, InitSpace};
+#[cfg(feature = "anchor")]
+use anchor_lang::Space;
+#[cfg(feature = "borsh")]
+use borsh::{BorshDeserialize, BorshSerialize};
 use paste::paste;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::typenum::{Integer, U10};
+use crate::display::fmt_decimal;
+use crate::error::FixError;
+use crate::num_traits::{Bounded, PrimInt, Zero};
+use crate::parse::{saturate_exp, Decimal, ParseFixError};
+use crate::rounding::{Discarded, Rounding};
+#[cfg(feature = "serde")]
+use crate::serialize::value_as_string::DecimalValue;
+#[cfg(feature = "serde")]
+use crate::serialize::{parse_decimal_value, write_decimal_value};
+use crate::typenum::{Integer, U10, Z0};
 use crate::Fix;
 
 /// Exponent mismatch converting a `FixValue` into a typed `Fix`.
@@ -27,34 +42,502 @@ impl Display for ExponentMismatch {
     }
 }
 
-impl Error for ExponentMismatch {}
+#[cfg(any(feature = "std", test))]
+impl std::error::Error for ExponentMismatch {}
 
+#[cfg(feature = "anchor")]
 impl From<ExponentMismatch> for AnchorError {
-    fn from(_: ExponentMismatch) -> AnchorError {
-        InvalidNumericConversion.into()
+    fn from(mismatch: ExponentMismatch) -> AnchorError {
+        FixError::from(mismatch).into()
     }
 }
 
+/// Splits `bits` into a sign and a magnitude, which fits every primitive.
+fn split<Bits: PrimInt>(bits: Bits) -> Result<(bool, u128), FixError> {
+    let negative = bits < Bits::zero();
+    let magnitude = if negative {
+        bits.to_i128().map(i128::unsigned_abs)
+    } else {
+        bits.to_u128()
+    };
+    magnitude
+        .map(|magnitude| (negative, magnitude))
+        .ok_or(FixError::out_of_range(negative))
+}
+
+/// Joins a sign and a magnitude, failing if they do not fit in _Bits_.
+fn join<Bits: PrimInt>(negative: bool, magnitude: u128) -> Result<Bits, FixError> {
+    if negative {
+        0i128.checked_sub_unsigned(magnitude).and_then(Bits::from)
+    } else {
+        Bits::from(magnitude)
+    }
+    .ok_or(FixError::out_of_range(negative))
+}
+
+/// Multiplies `bits` by 10<sup>`shift`</sup>, or `None` on overflow.
+fn upscale<Bits: PrimInt>(bits: Bits, shift: u32) -> Option<Bits> {
+    if bits.is_zero() {
+        return Some(bits);
+    }
+    10u128
+        .checked_pow(shift)
+        .and_then(Bits::from)
+        .and_then(|ratio| bits.checked_mul(&ratio))
+}
+
+/// Divides `magnitude` by _divisor × 10<sup>shift</sup>_, rounding a quotient of the given sign.
+/// Returns the quotient and whether it was rounded.
+fn div_pow10(
+    magnitude: u128,
+    divisor: u128,
+    shift: u32,
+    negative: bool,
+    rounding: Rounding,
+) -> (u128, bool) {
+    let (quotient, remainder) = (magnitude / divisor, magnitude % divisor);
+    let (quotient, discarded) = match 10u128.checked_pow(shift) {
+        _ if shift == 0 => (quotient, Discarded::of_remainder(remainder, divisor, false)),
+        Some(ratio) => (
+            quotient / ratio,
+            Discarded::of_remainder(quotient % ratio, ratio, remainder != 0),
+        ),
+        // The ratio exceeds twice any quotient, which therefore rounds like a small fraction.
+        None if magnitude == 0 => (0, Discarded::Zero),
+        None => (0, Discarded::BelowHalf),
+    };
+    let bump = rounding.bumps(negative, quotient % 2 == 1, discarded);
+    (quotient + u128::from(bump), discarded != Discarded::Zero)
+}
+
+/// Converts `bits` from 10<sup>`from`</sup> to 10<sup>`to`</sup>, rounding with `rounding`.
+/// Returns the result and whether it was rounded.
+fn rescale<Bits: PrimInt>(
+    bits: Bits,
+    from: i32,
+    to: i32,
+    rounding: Rounding,
+) -> Result<(Bits, bool), FixError> {
+    let shift = from.abs_diff(to);
+    if from >= to {
+        return upscale(bits, shift)
+            .map(|bits| (bits, false))
+            .ok_or(FixError::out_of_range(bits < Bits::zero()));
+    }
+    let (negative, magnitude) = split(bits)?;
+    let (magnitude, rounded) = div_pow10(magnitude, 1, shift, negative, rounding);
+    join(negative, magnitude).map(|bits| (bits, rounded))
+}
+
+/// Multiplies `bits` at 10<sup>`from`</sup> into units of a finer 10<sup>`to`</sup>, or `None` on
+/// overflow.
+fn align<Bits: PrimInt>((bits, from): (Bits, i8), to: i8) -> Option<Bits> {
+    upscale(bits, from.abs_diff(to).into())
+}
+
+/// Adds exactly at the finer exponent.
+fn add<Bits: PrimInt>(lhs: (Bits, i8), rhs: (Bits, i8)) -> Result<(Bits, i8), FixError> {
+    let exp = lhs.1.min(rhs.1);
+    let lhs = align(lhs, exp).ok_or(FixError::out_of_range(lhs.0 < Bits::zero()))?;
+    let rhs = align(rhs, exp).ok_or(FixError::out_of_range(rhs.0 < Bits::zero()))?;
+    lhs.checked_add(&rhs)
+        .map(|bits| (bits, exp))
+        .ok_or(FixError::out_of_range(rhs < Bits::zero()))
+}
+
+/// Subtracts exactly at the finer exponent.
+fn sub<Bits: PrimInt>(lhs: (Bits, i8), rhs: (Bits, i8)) -> Result<(Bits, i8), FixError> {
+    let exp = lhs.1.min(rhs.1);
+    let lhs = align(lhs, exp).ok_or(FixError::out_of_range(lhs.0 < Bits::zero()))?;
+    let rhs = align(rhs, exp).ok_or(FixError::out_of_range(rhs.0 > Bits::zero()))?;
+    lhs.checked_sub(&rhs)
+        .map(|bits| (bits, exp))
+        .ok_or(FixError::out_of_range(rhs > Bits::zero()))
+}
+
+/// Multiplies exactly, adding exponents.
+fn mul<Bits: PrimInt>(
+    (lhs, lhs_exp): (Bits, i8),
+    (rhs, rhs_exp): (Bits, i8),
+) -> Result<(Bits, i8), FixError> {
+    let negative = (lhs < Bits::zero()) != (rhs < Bits::zero());
+    let bits = lhs
+        .checked_mul(&rhs)
+        .ok_or(FixError::out_of_range(negative))?;
+    // An exponent beyond `i8` moves into the bits, which fails unless it only drops zeros.
+    let exp = lhs_exp.saturating_add(rhs_exp);
+    match rescale(
+        bits,
+        i32::from(lhs_exp) + i32::from(rhs_exp),
+        exp.into(),
+        Rounding::TowardZero,
+    )? {
+        (_, true) => Err(FixError::PrecisionLoss),
+        (bits, false) => Ok((bits, exp)),
+    }
+}
+
+/// Divides into a quotient at 10<sup>`exp`</sup>, rounding with `rounding`.
+fn div<Bits: PrimInt>(
+    (lhs, lhs_exp): (Bits, i8),
+    (rhs, rhs_exp): (Bits, i8),
+    exp: i8,
+    rounding: Rounding,
+) -> Result<Bits, FixError> {
+    if rhs.is_zero() {
+        return Err(FixError::DivisionByZero);
+    }
+    let (lhs_negative, dividend) = split(lhs)?;
+    let (rhs_negative, divisor) = split(rhs)?;
+    let negative = lhs_negative != rhs_negative;
+    // lhs × 10^lhs_exp ÷ (rhs × 10^rhs_exp) = lhs ÷ rhs × 10^shift units of 10^exp.
+    let shift = i32::from(lhs_exp) - i32::from(rhs_exp) - i32::from(exp);
+    let (quotient, _) = if shift >= 0 {
+        let dividend =
+            upscale(dividend, shift.unsigned_abs()).ok_or(FixError::out_of_range(negative))?;
+        div_pow10(dividend, divisor, 0, negative, rounding)
+    } else {
+        div_pow10(dividend, divisor, shift.unsigned_abs(), negative, rounding)
+    };
+    join(negative, quotient)
+}
+
+/// Compares numeric values. A side that overflows _Bits_ at the finer exponent is further from
+/// zero than any value that fits.
+fn cmp_value<Bits: PrimInt>((lhs, lhs_exp): (Bits, i8), (rhs, rhs_exp): (Bits, i8)) -> Ordering {
+    let shift = lhs_exp.abs_diff(rhs_exp).into();
+    if lhs_exp >= rhs_exp {
+        upscale(lhs, shift).map_or(lhs.cmp(&Bits::zero()), |lhs| lhs.cmp(&rhs))
+    } else {
+        upscale(rhs, shift).map_or(Bits::zero().cmp(&rhs), |rhs| lhs.cmp(&rhs))
+    }
+}
+
+/// A `FixValue` compared, ordered and hashed by numeric value rather than by bits and exponent,
+/// e.g. as a `HashMap` or `BTreeMap` key.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use fix::prelude::*;
+/// let prices: HashSet<_> = [UFixValue64::new(5, 0), UFixValue64::new(50, -1)]
+///     .into_iter()
+///     .map(Canonical)
+///     .collect();
+/// assert_eq!(prices.len(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Canonical<V>(pub V);
+
 macro_rules! impl_fix_value {
-    ($sign:ident, $bits:expr) => {
+    ($sign:ident, $bits:tt) => {
         paste! {
            /// A value-space `Fix` where base is always 10 and bits are a concrete type.
            /// Intended for serialized storage in Solana accounts where generics won't work.
-            #[derive(PartialEq, Eq, Copy, Clone, Debug, Default, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize, InitSpace)]
+            #[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+            #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+            #[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
             pub struct [<$sign FixValue $bits>] {
-                pub bits: [<$sign:lower $bits>],
+                pub bits: [<$sign:lower $bits:lower>],
                 pub exp: i8,
             }
 
+            // Anchor's `InitSpace` derive cannot size `usize` and `isize` fields.
+            #[cfg(feature = "anchor")]
+            impl Space for [<$sign FixValue $bits>] {
+                const INIT_SPACE: usize =
+                    <Fix<[<$sign:lower $bits:lower>], U10, Z0> as Space>::INIT_SPACE + 1;
+            }
+
             impl [<$sign FixValue $bits>] {
-                #[must_use] pub fn new(bits: [<$sign:lower $bits>], exp: i8) -> Self {
+                #[must_use] pub fn new(bits: [<$sign:lower $bits:lower>], exp: i8) -> Self {
+                    Self { bits, exp }
+                }
+
+                /// Converts into a typed `Fix` at any _Exp_, rounding with `rounding` if it is
+                /// coarser than `exp`. Returns the result and whether it was rounded.
+                ///
+                /// # Errors
+                ///
+                /// [`FixError::Overflow`] or [`FixError::Underflow`] if the result does not fit.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use fix::prelude::*;
+                /// use fix::typenum::{P1, Z0};
+                #[doc = concat!("let value = ", stringify!([<$sign FixValue $bits>]), "::new(50, -1);")]
+                /// let (fix, rounded) = value.try_into_rescaled::<N1>(Rounding::HalfEven)?;
+                /// assert_eq!((fix.bits, rounded), (50, false));
+                /// let (fix, rounded) = value.try_into_rescaled::<Z0>(Rounding::Floor)?;
+                /// assert_eq!((fix.bits, rounded), (5, false));
+                /// let (fix, rounded) = value.try_into_rescaled::<P1>(Rounding::Ceil)?;
+                /// assert_eq!((fix.bits, rounded), (1, true));
+                /// # Ok::<(), FixError>(())
+                /// ```
+                pub fn try_into_rescaled<Exp: Integer>(
+                    self,
+                    rounding: Rounding,
+                ) -> Result<(Fix<[<$sign:lower $bits:lower>], U10, Exp>, bool), FixError> {
+                    rescale(self.bits, self.exp.into(), Exp::to_i32(), rounding)
+                        .map(|(bits, rounded)| (Fix::new(bits), rounded))
+                }
+
+                /// Adds exactly at the finer of both exponents.
+                ///
+                /// # Errors
+                ///
+                /// [`FixError::Overflow`] or [`FixError::Underflow`] if either operand at that
+                /// exponent, or the sum, does not fit.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use fix::prelude::*;
+                #[doc = concat!("let sum = ", stringify!([<$sign FixValue $bits>]), "::new(12, -1).try_add(", stringify!([<$sign FixValue $bits>]), "::new(3, 0))?;")]
+                #[doc = concat!("assert_eq!(sum, ", stringify!([<$sign FixValue $bits>]), "::new(42, -1));")]
+                /// # Ok::<(), FixError>(())
+                /// ```
+                pub fn try_add(self, rhs: Self) -> Result<Self, FixError> {
+                    add((self.bits, self.exp), (rhs.bits, rhs.exp))
+                        .map(|(bits, exp)| Self { bits, exp })
+                }
+
+                /// Subtracts exactly at the finer of both exponents.
+                ///
+                /// # Errors
+                ///
+                /// [`FixError::Overflow`] or [`FixError::Underflow`] if either operand at that
+                /// exponent, or the difference, does not fit.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use fix::prelude::*;
+                #[doc = concat!("let difference = ", stringify!([<$sign FixValue $bits>]), "::new(5, 0).try_sub(", stringify!([<$sign FixValue $bits>]), "::new(12, -1))?;")]
+                #[doc = concat!("assert_eq!(difference, ", stringify!([<$sign FixValue $bits>]), "::new(38, -1));")]
+                /// # Ok::<(), FixError>(())
+                /// ```
+                pub fn try_sub(self, rhs: Self) -> Result<Self, FixError> {
+                    sub((self.bits, self.exp), (rhs.bits, rhs.exp))
+                        .map(|(bits, exp)| Self { bits, exp })
+                }
+
+                /// Multiplies exactly, adding exponents.
+                ///
+                /// # Errors
+                ///
+                /// [`FixError::Overflow`] or [`FixError::Underflow`] if the product does not fit,
+                /// or [`FixError::PrecisionLoss`] if its exponent is below `i8::MIN` and it cannot
+                /// be rescaled without loss.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use fix::prelude::*;
+                #[doc = concat!("let product = ", stringify!([<$sign FixValue $bits>]), "::new(12, -1).try_mul(", stringify!([<$sign FixValue $bits>]), "::new(5, 1))?;")]
+                #[doc = concat!("assert_eq!(product, ", stringify!([<$sign FixValue $bits>]), "::new(60, 0));")]
+                /// # Ok::<(), FixError>(())
+                /// ```
+                pub fn try_mul(self, rhs: Self) -> Result<Self, FixError> {
+                    mul((self.bits, self.exp), (rhs.bits, rhs.exp))
+                        .map(|(bits, exp)| Self { bits, exp })
+                }
+
+                /// Divides into a quotient at 10<sup>`exp`</sup>, rounding with `rounding`.
+                ///
+                /// # Errors
+                ///
+                /// [`FixError::DivisionByZero`] if `rhs` is zero, otherwise
+                /// [`FixError::Overflow`] or [`FixError::Underflow`] if the quotient, or `self`
+                /// rescaled to its precision as a 128-bit magnitude, does not fit.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use fix::prelude::*;
+                #[doc = concat!("let one = ", stringify!([<$sign FixValue $bits>]), "::new(1, 0);")]
+                #[doc = concat!("let third = one.try_div(", stringify!([<$sign FixValue $bits>]), "::new(3, 0), -2, Rounding::Ceil)?;")]
+                #[doc = concat!("assert_eq!(third, ", stringify!([<$sign FixValue $bits>]), "::new(34, -2));")]
+                /// # Ok::<(), FixError>(())
+                /// ```
+                pub fn try_div(self, rhs: Self, exp: i8, rounding: Rounding) -> Result<Self, FixError> {
+                    div((self.bits, self.exp), (rhs.bits, rhs.exp), exp, rounding)
+                        .map(|bits| Self { bits, exp })
+                }
+
+                /// Compares numeric values regardless of exponents, unlike the structural
+                /// `PartialEq`. Never overflows.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use core::cmp::Ordering;
+                /// use fix::prelude::*;
+                #[doc = concat!("let half = ", stringify!([<$sign FixValue $bits>]), "::new(5, -1);")]
+                #[doc = concat!("assert_eq!(half.cmp_value(&", stringify!([<$sign FixValue $bits>]), "::new(50, -2)), Ordering::Equal);")]
+                #[doc = concat!("assert_eq!(half.cmp_value(&", stringify!([<$sign FixValue $bits>]), "::new(1, 0)), Ordering::Less);")]
+                /// ```
+                #[must_use]
+                pub fn cmp_value(&self, other: &Self) -> Ordering {
+                    cmp_value((self.bits, self.exp), (other.bits, other.exp))
+                }
+
+                /// Whether both have the same numeric value, unlike the structural `PartialEq`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use fix::prelude::*;
+                #[doc = concat!("let five = ", stringify!([<$sign FixValue $bits>]), "::new(5, 0);")]
+                #[doc = concat!("assert!(five.eq_value(&", stringify!([<$sign FixValue $bits>]), "::new(50, -1)));")]
+                #[doc = concat!("assert_ne!(five, ", stringify!([<$sign FixValue $bits>]), "::new(50, -1));")]
+                /// ```
+                #[must_use]
+                pub fn eq_value(&self, other: &Self) -> bool {
+                    self.cmp_value(other) == Ordering::Equal
+                }
+
+                /// Strips trailing decimal zeros into the exponent, up to `i8::MAX`. Zero becomes
+                /// `0 × 10⁰`. Values equal by [`Self::eq_value`] normalize to the same bits and
+                /// exponent.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use fix::prelude::*;
+                #[doc = concat!("let value = ", stringify!([<$sign FixValue $bits>]), "::new(120, -3);")]
+                #[doc = concat!("assert_eq!(value.normalize(), ", stringify!([<$sign FixValue $bits>]), "::new(12, -2));")]
+                /// ```
+                #[must_use]
+                pub fn normalize(self) -> Self {
+                    let Self { mut bits, mut exp } = self;
+                    if bits == 0 {
+                        return Self { bits, exp: 0 };
+                    }
+                    while bits % 10 == 0 && exp < i8::MAX {
+                        bits /= 10;
+                        exp += 1;
+                    }
                     Self { bits, exp }
                 }
+
+                /// Parses decimal notation at 10<sup>`exp`</sup>, rounding digits beyond it with
+                /// `rounding`.
+                ///
+                /// # Errors
+                ///
+                /// Fails on malformed input, or if the rounded value does not fit.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use fix::prelude::*;
+                #[doc = concat!("let value = ", stringify!([<$sign FixValue $bits>]), "::parse_with_exp(\"1.25\", -1, Rounding::HalfEven);")]
+                #[doc = concat!("assert_eq!(value, Ok(", stringify!([<$sign FixValue $bits>]), "::new(12, -1)));")]
+                #[doc = concat!("let value = ", stringify!([<$sign FixValue $bits>]), "::parse_with_exp(\"0.5\", -2, Rounding::Floor);")]
+                #[doc = concat!("assert_eq!(value, Ok(", stringify!([<$sign FixValue $bits>]), "::new(50, -2)));")]
+                /// ```
+                pub fn parse_with_exp(
+                    s: &str,
+                    exp: i8,
+                    rounding: Rounding,
+                ) -> Result<Self, ParseFixError> {
+                    Decimal::parse(s)?
+                        .to_bits(exp.into(), Some(rounding))
+                        .map(|bits| Self { bits, exp })
+                }
+            }
+
+            /// Writes the exact decimal value like `Fix<Bits, U10, Exp>`, with zeros for a
+            /// positive exponent. A shorter `{:.N}` precision rounds with
+            /// [`Rounding::HalfEven`].
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use fix::prelude::*;
+            #[doc = concat!("assert_eq!(", stringify!([<$sign FixValue $bits>]), "::new(15, -2).to_string(), \"0.15\");")]
+            #[doc = concat!("assert_eq!(", stringify!([<$sign FixValue $bits>]), "::new(15, 2).to_string(), \"1500\");")]
+            #[doc = concat!("assert_eq!(format!(\"{:.1}\", ", stringify!([<$sign FixValue $bits>]), "::new(15, -2)), \"0.2\");")]
+            /// ```
+            impl Display for [<$sign FixValue $bits>] {
+                fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                    fmt_decimal(f, &self.bits, self.exp.into(), Rounding::HalfEven)
+                }
+            }
+
+            /// Parses decimal notation like `Fix<Bits, U10, Exp>`, at the coarsest exponent
+            /// that holds the value exactly, as [`normalize`] would leave it.
+            ///
+            #[doc = concat!("[`normalize`]: ", stringify!([<$sign FixValue $bits>]), "::normalize")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use fix::prelude::*;
+            #[doc = concat!("assert_eq!(\"1.50\".parse(), Ok(", stringify!([<$sign FixValue $bits>]), "::new(15, -1)));")]
+            #[doc = concat!("assert_eq!(\"1200\".parse(), Ok(", stringify!([<$sign FixValue $bits>]), "::new(12, 2)));")]
+            /// ```
+            impl FromStr for [<$sign FixValue $bits>] {
+                type Err = ParseFixError;
+                fn from_str(s: &str) -> Result<Self, ParseFixError> {
+                    let decimal = Decimal::parse(s)?;
+                    let exp = saturate_exp(decimal.significant_exp());
+                    decimal.to_bits(exp.into(), None).map(|bits| Self { bits, exp })
+                }
+            }
+
+            impl From<[<$sign FixValue $bits>]> for Canonical<[<$sign FixValue $bits>]> {
+                fn from(value: [<$sign FixValue $bits>]) -> Self {
+                    Self(value)
+                }
+            }
+
+            impl PartialEq for Canonical<[<$sign FixValue $bits>]> {
+                fn eq(&self, other: &Self) -> bool {
+                    self.0.eq_value(&other.0)
+                }
+            }
+
+            impl Eq for Canonical<[<$sign FixValue $bits>]> {}
+
+            impl PartialOrd for Canonical<[<$sign FixValue $bits>]> {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for Canonical<[<$sign FixValue $bits>]> {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.0.cmp_value(&other.0)
+                }
+            }
+
+            impl Hash for Canonical<[<$sign FixValue $bits>]> {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    let Self(value) = self;
+                    let [<$sign FixValue $bits>] { bits, exp } = value.normalize();
+                    bits.hash(state);
+                    exp.hash(state);
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl DecimalValue for [<$sign FixValue $bits>] {
+                fn fmt_decimal(&self, f: &mut Formatter) -> fmt::Result {
+                    write_decimal_value(f, &self.bits, self.exp)
+                }
+
+                fn parse_decimal(s: &str) -> Result<Self, ParseFixError> {
+                    parse_decimal_value(s).map(|(bits, exp)| Self { bits, exp })
+                }
             }
 
             impl<Bits, Exp> From<Fix<Bits, U10, Exp>> for [<$sign FixValue $bits>]
             where
-                Bits: Into<[<$sign:lower $bits>]>,
+                Bits: Into<[<$sign:lower $bits:lower>]>,
                 Exp: Integer,
             {
                 fn from(fix: Fix<Bits, U10, Exp>) -> Self {
@@ -67,7 +550,7 @@ macro_rules! impl_fix_value {
 
             impl<Bits, Exp> TryFrom<[<$sign FixValue $bits>]> for Fix<Bits, U10, Exp>
             where
-                Bits: From<[<$sign:lower $bits>]>,
+                Bits: From<[<$sign:lower $bits:lower>]>,
                 Exp: Integer,
             {
               type Error = ExponentMismatch;
@@ -90,60 +573,601 @@ impl_fix_value!(U, 16);
 impl_fix_value!(U, 32);
 impl_fix_value!(U, 64);
 impl_fix_value!(U, 128);
+impl_fix_value!(U, Size);
 impl_fix_value!(I, 8);
 impl_fix_value!(I, 16);
 impl_fix_value!(I, 32);
 impl_fix_value!(I, 64);
 impl_fix_value!(I, 128);
+impl_fix_value!(I, Size);
+
+/// Converts bits like [`Fix::try_narrow`].
+fn try_narrow<Bits, ToBits>(bits: Bits) -> Result<ToBits, FixError>
+where
+    Bits: Zero + PartialOrd,
+    ToBits: TryFrom<Bits> + Bounded + Zero + PartialEq,
+{
+    Fix::<Bits, U10, Z0>::new(bits)
+        .try_narrow()
+        .map(|fix| fix.bits)
+}
+
+/// Lossless conversions, wherever the bits convert with `From`.
+macro_rules! impl_fix_value_from {
+    ($from:ident => $($to:ident),+) => {
+        $(
+            impl From<$from> for $to {
+                fn from(value: $from) -> Self {
+                    Self {
+                        bits: value.bits.into(),
+                        exp: value.exp,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+/// Checked conversions, failing like [`Fix::try_narrow`].
+macro_rules! impl_fix_value_try_from {
+    ($from:ident => $($to:ident),+) => {
+        $(
+            impl TryFrom<$from> for $to {
+                type Error = FixError;
+                fn try_from(value: $from) -> Result<Self, FixError> {
+                    try_narrow(value.bits).map(|bits| Self {
+                        bits,
+                        exp: value.exp,
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_fix_value_from!(UFixValue8 => UFixValue16, UFixValue32, UFixValue64, UFixValue128,
+    UFixValueSize, IFixValue16, IFixValue32, IFixValue64, IFixValue128, IFixValueSize);
+impl_fix_value_try_from!(UFixValue8 => IFixValue8);
+impl_fix_value_from!(UFixValue16 => UFixValue32, UFixValue64, UFixValue128, UFixValueSize,
+    IFixValue32, IFixValue64, IFixValue128);
+impl_fix_value_try_from!(UFixValue16 => UFixValue8, IFixValue8, IFixValue16, IFixValueSize);
+impl_fix_value_from!(UFixValue32 => UFixValue64, UFixValue128, IFixValue64, IFixValue128);
+impl_fix_value_try_from!(UFixValue32 => UFixValue8, UFixValue16, UFixValueSize, IFixValue8,
+    IFixValue16, IFixValue32, IFixValueSize);
+impl_fix_value_from!(UFixValue64 => UFixValue128, IFixValue128);
+impl_fix_value_try_from!(UFixValue64 => UFixValue8, UFixValue16, UFixValue32, UFixValueSize,
+    IFixValue8, IFixValue16, IFixValue32, IFixValue64, IFixValueSize);
+impl_fix_value_try_from!(UFixValue128 => UFixValue8, UFixValue16, UFixValue32, UFixValue64,
+    UFixValueSize, IFixValue8, IFixValue16, IFixValue32, IFixValue64, IFixValue128, IFixValueSize);
+impl_fix_value_try_from!(UFixValueSize => UFixValue8, UFixValue16, UFixValue32, UFixValue64,
+    UFixValue128, IFixValue8, IFixValue16, IFixValue32, IFixValue64, IFixValue128, IFixValueSize);
+impl_fix_value_from!(IFixValue8 => IFixValue16, IFixValue32, IFixValue64, IFixValue128,
+    IFixValueSize);
+impl_fix_value_try_from!(IFixValue8 => UFixValue8, UFixValue16, UFixValue32, UFixValue64,
+    UFixValue128, UFixValueSize);
+impl_fix_value_from!(IFixValue16 => IFixValue32, IFixValue64, IFixValue128, IFixValueSize);
+impl_fix_value_try_from!(IFixValue16 => UFixValue8, UFixValue16, UFixValue32, UFixValue64,
+    UFixValue128, UFixValueSize, IFixValue8);
+impl_fix_value_from!(IFixValue32 => IFixValue64, IFixValue128);
+impl_fix_value_try_from!(IFixValue32 => UFixValue8, UFixValue16, UFixValue32, UFixValue64,
+    UFixValue128, UFixValueSize, IFixValue8, IFixValue16, IFixValueSize);
+impl_fix_value_from!(IFixValue64 => IFixValue128);
+impl_fix_value_try_from!(IFixValue64 => UFixValue8, UFixValue16, UFixValue32, UFixValue64,
+    UFixValue128, UFixValueSize, IFixValue8, IFixValue16, IFixValue32, IFixValueSize);
+impl_fix_value_try_from!(IFixValue128 => UFixValue8, UFixValue16, UFixValue32, UFixValue64,
+    UFixValue128, UFixValueSize, IFixValue8, IFixValue16, IFixValue32, IFixValue64, IFixValueSize);
+impl_fix_value_try_from!(IFixValueSize => UFixValue8, UFixValue16, UFixValue32, UFixValue64,
+    UFixValue128, UFixValueSize, IFixValue8, IFixValue16, IFixValue32, IFixValue64, IFixValue128);
 
 #[cfg(test)]
 mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeSet, HashSet};
+
     use super::*;
     use crate::aliases::si::Kilo;
+    use crate::typenum::{N1, N128, N2, P127, P2, Z0};
     use anyhow::Result;
+    #[cfg(all(feature = "borsh", feature = "std"))]
     use borsh::to_vec;
 
     macro_rules! fix_value_tests {
-        ($sign:ident, $bits:expr) => {
+        ($sign:ident, $bits:tt) => {
             paste! {
                 #[test]
-                fn [<roundtrip_into_ $sign:lower $bits>]() -> Result<()> {
-                    let start = Kilo::new([<69 $sign:lower $bits>]);
+                fn [<roundtrip_into_ $sign:lower $bits:lower>]() -> Result<()> {
+                    let start = Kilo::new([<69 $sign:lower $bits:lower>]);
                     let there: [<$sign FixValue $bits>] = start.into();
-                    let back: Kilo<[<$sign:lower $bits>]> = there.try_into()?;
+                    let back: Kilo<[<$sign:lower $bits:lower>]> = there.try_into()?;
                     assert_eq!(there, [<$sign FixValue $bits>]::new(69, 3));
                     Ok(assert_eq!(start, back))
                 }
 
+                #[cfg(all(feature = "borsh", feature = "std"))]
                 #[test]
-                fn [<roundtrip_serialize_ $sign:lower $bits>]() -> Result<()> {
+                fn [<roundtrip_serialize_ $sign:lower $bits:lower>]() -> Result<()> {
                     let start = [<$sign FixValue $bits>]::new(20, -2);
                     let bytes = to_vec(&start)?;
-                    let back = AnchorDeserialize::deserialize(&mut bytes.as_slice())?;
+                    let back = BorshDeserialize::deserialize(&mut bytes.as_slice())?;
                     Ok(assert_eq!(start, back))
                 }
 
                 #[test]
-                fn [<wrong_exp_should_fail_ $sign:lower $bits>]() -> Result<()> {
+                fn [<wrong_exp_should_fail_ $sign:lower $bits:lower>]() -> Result<()> {
                     let pow11 = [<$sign FixValue $bits>]::new(42, -11);
-                    let wrong = TryInto::<Kilo<[<$sign:lower $bits>]>>::try_into(pow11);
+                    let wrong = TryInto::<Kilo<[<$sign:lower $bits:lower>]>>::try_into(pow11);
                     Ok(assert_eq!(
                         Err(ExponentMismatch { expected: 3, actual: -11 }),
                         wrong
                     ))
                 }
+
+                #[test]
+                fn [<rescale_exact_ $sign:lower $bits:lower>]() -> Result<()> {
+                    let value = [<$sign FixValue $bits>]::new(10, -1);
+                    let (fix, rounded) = value.try_into_rescaled::<N2>(Rounding::Floor)?;
+                    assert_eq!((fix.bits, rounded), (100, false));
+                    let (fix, rounded) = value.try_into_rescaled::<Z0>(Rounding::Floor)?;
+                    Ok(assert_eq!((fix.bits, rounded), (1, false)))
+                }
+
+                #[test]
+                fn [<rescale_rounds_ $sign:lower $bits:lower>]() -> Result<()> {
+                    let value = [<$sign FixValue $bits>]::new(125, -1);
+                    let (fix, rounded) = value.try_into_rescaled::<Z0>(Rounding::HalfEven)?;
+                    assert_eq!((fix.bits, rounded), (12, true));
+                    let (fix, rounded) = value.try_into_rescaled::<Z0>(Rounding::HalfUp)?;
+                    Ok(assert_eq!((fix.bits, rounded), (13, true)))
+                }
+
+                #[test]
+                fn [<arithmetic_ $sign:lower $bits:lower>]() -> Result<()> {
+                    let a = [<$sign FixValue $bits>]::new(15, -1);
+                    let b = [<$sign FixValue $bits>]::new(2, 0);
+                    assert_eq!(a.try_add(b)?, [<$sign FixValue $bits>]::new(35, -1));
+                    assert_eq!(b.try_sub(a)?, [<$sign FixValue $bits>]::new(5, -1));
+                    assert_eq!(a.try_mul(b)?, [<$sign FixValue $bits>]::new(30, -1));
+                    assert_eq!(
+                        a.try_div(b, -2, Rounding::Floor)?,
+                        [<$sign FixValue $bits>]::new(75, -2)
+                    );
+                    assert_eq!(
+                        a.try_div(b, 0, Rounding::HalfEven)?,
+                        [<$sign FixValue $bits>]::new(1, 0)
+                    );
+                    assert_eq!(
+                        a.try_div([<$sign FixValue $bits>]::new(0, 3), 0, Rounding::Floor),
+                        Err(FixError::DivisionByZero)
+                    );
+                    Ok(())
+                }
+
+                #[test]
+                fn [<arithmetic_overflows_ $sign:lower $bits:lower>]() {
+                    let max = [<$sign FixValue $bits>]::new([<$sign:lower $bits:lower>]::MAX, 0);
+                    let one = [<$sign FixValue $bits>]::new(1, 0);
+                    let tenth = [<$sign FixValue $bits>]::new(1, -1);
+                    assert_eq!(max.try_add(one), Err(FixError::Overflow));
+                    assert_eq!(max.try_add(tenth), Err(FixError::Overflow));
+                    assert_eq!(tenth.try_sub(max), Err(FixError::Underflow));
+                    assert_eq!(max.try_mul(max), Err(FixError::Overflow));
+                    assert_eq!(
+                        max.try_div(tenth, 0, Rounding::Floor),
+                        Err(FixError::Overflow)
+                    );
+                }
+
+                #[test]
+                fn [<cmp_value_ $sign:lower $bits:lower>]() {
+                    let max = [<$sign FixValue $bits>]::new([<$sign:lower $bits:lower>]::MAX, 0);
+                    let ten = [<$sign FixValue $bits>]::new(1, 1);
+                    assert_eq!(ten.cmp_value(&[<$sign FixValue $bits>]::new(10, 0)), Ordering::Equal);
+                    assert_eq!(max.cmp_value(&[<$sign FixValue $bits>]::new(1, 127)), Ordering::Less);
+                    assert_eq!(max.cmp_value(&[<$sign FixValue $bits>]::new(0, 127)), Ordering::Greater);
+                    assert_eq!(max.cmp_value(&ten), Ordering::Greater);
+                    assert_eq!(
+                        [<$sign FixValue $bits>]::new(0, -128).cmp_value(&[<$sign FixValue $bits>]::new(0, 127)),
+                        Ordering::Equal
+                    );
+                }
+
+                #[test]
+                fn [<display_parse_ $sign:lower $bits:lower>]() -> Result<()> {
+                    for (value, text) in [
+                        ([<$sign FixValue $bits>]::new(5, -2), "0.05"),
+                        ([<$sign FixValue $bits>]::new(12, 1), "120"),
+                        ([<$sign FixValue $bits>]::new(0, 0), "0"),
+                        ([<$sign FixValue $bits>]::new(1, 0), "1"),
+                    ] {
+                        assert_eq!(value.to_string(), text);
+                        assert_eq!(text.parse::<[<$sign FixValue $bits>]>()?, value);
+                    }
+                    assert_eq!(
+                        "0.50".parse::<[<$sign FixValue $bits>]>()?,
+                        [<$sign FixValue $bits>]::new(5, -1)
+                    );
+                    assert_eq!(
+                        [<$sign FixValue $bits>]::parse_with_exp("0.05", -1, Rounding::HalfEven)?,
+                        [<$sign FixValue $bits>]::new(0, -1)
+                    );
+                    Ok(())
+                }
+
+                #[test]
+                fn [<canonical_ $sign:lower $bits:lower>]() {
+                    let value = [<$sign FixValue $bits>]::new(20, -1);
+                    let same = [<$sign FixValue $bits>]::new(2, 0);
+                    assert_eq!(value.normalize(), same);
+                    assert!(value.eq_value(&same));
+                    assert_eq!(Canonical(value), Canonical(same));
+                    assert_eq!(hash(Canonical(value)), hash(Canonical(same)));
+                    assert!(Canonical(value) < Canonical([<$sign FixValue $bits>]::new(21, -1)));
+
+                    let zero = [<$sign FixValue $bits>]::new(0, -7);
+                    assert_eq!(zero.normalize(), [<$sign FixValue $bits>]::new(0, 0));
+                    assert_eq!(hash(Canonical(zero)), hash(Canonical([<$sign FixValue $bits>]::default())));
+
+                    let top = [<$sign FixValue $bits>]::new(100, 126);
+                    assert_eq!(top.normalize(), [<$sign FixValue $bits>]::new(10, 127));
+                    assert_eq!(Canonical(top), Canonical([<$sign FixValue $bits>]::new(10, 127)));
+                }
+
+                #[test]
+                fn [<rescale_overflows_ $sign:lower $bits:lower>]() {
+                    let value = [<$sign FixValue $bits>]::new([<$sign:lower $bits:lower>]::MAX, 0);
+                    assert_eq!(
+                        value.try_into_rescaled::<N1>(Rounding::Floor),
+                        Err(FixError::Overflow)
+                    );
+                }
             }
         };
     }
 
+    #[test]
+    fn rescale_negative() -> Result<()> {
+        let value = IFixValue64::new(-125, -1);
+        let (fix, rounded) = value.try_into_rescaled::<Z0>(Rounding::Floor)?;
+        assert_eq!((fix.bits, rounded), (-13, true));
+        let (fix, rounded) = value.try_into_rescaled::<Z0>(Rounding::TowardZero)?;
+        assert_eq!((fix.bits, rounded), (-12, true));
+        let value = IFixValue8::new(i8::MIN, 0);
+        assert_eq!(
+            value.try_into_rescaled::<N1>(Rounding::Floor),
+            Err(FixError::Underflow)
+        );
+        let (fix, rounded) = value.try_into_rescaled::<P2>(Rounding::HalfEven)?;
+        assert_eq!((fix.bits, rounded), (-1, true));
+        Ok(())
+    }
+
+    fn hash(value: impl Hash) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn display_matches_fix() {
+        use crate::aliases::decimal::{IFix64, UFix64};
+        use crate::typenum::{N3, P3};
+
+        let fix = IFix64::<N3>::new(-1_234);
+        let value = IFixValue64::from(fix);
+        assert_eq!(value.to_string(), fix.to_string());
+        assert_eq!(format!("{value:.1}"), format!("{fix:.1}"));
+        assert_eq!(format!("{value:>+10.4}"), format!("{fix:>+10.4}"));
+        let fix = UFix64::<P3>::new(25);
+        assert_eq!(UFixValue64::from(fix).to_string(), "25000");
+        assert_eq!(
+            format!("{:08.2}", UFixValue64::from(fix)),
+            format!("{fix:08.2}")
+        );
+    }
+
+    #[test]
+    fn parse_picks_coarsest_exponent() -> Result<()> {
+        assert_eq!("-0.0120".parse::<IFixValue32>()?, IFixValue32::new(-12, -3));
+        assert_eq!("15e2".parse::<UFixValue32>()?, UFixValue32::new(15, 2));
+        assert_eq!("-000.000".parse::<IFixValue32>()?, IFixValue32::new(0, 0));
+        assert_eq!(
+            "1e130".parse::<UFixValue16>()?,
+            UFixValue16::new(1_000, 127)
+        );
+        assert_eq!("1e-128".parse::<UFixValue8>()?, UFixValue8::new(1, -128));
+        assert_eq!(
+            "1e-129".parse::<UFixValue8>(),
+            Err(ParseFixError::TooManyDecimals)
+        );
+        assert_eq!("1e140".parse::<UFixValue16>(), Err(ParseFixError::Overflow));
+        assert_eq!("-1".parse::<UFixValue64>(), Err(ParseFixError::Overflow));
+        assert_eq!(
+            "1.2.3".parse::<UFixValue64>(),
+            Err(ParseFixError::InvalidDigit)
+        );
+        assert_eq!("".parse::<UFixValue64>(), Err(ParseFixError::Empty));
+
+        let long = "0.000000000000000000000000000000000000000000000000000000000000000000000000001";
+        let value = long.parse::<UFixValue8>()?;
+        assert_eq!(value, UFixValue8::new(1, -75));
+        assert_eq!(value.to_string(), long);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_with_exp_rounds() -> Result<()> {
+        assert_eq!(
+            IFixValue64::parse_with_exp("-1.25", -1, Rounding::Floor)?,
+            IFixValue64::new(-13, -1)
+        );
+        assert_eq!(
+            IFixValue64::parse_with_exp("-1.25", -1, Rounding::HalfEven)?,
+            IFixValue64::new(-12, -1)
+        );
+        assert_eq!(
+            UFixValue64::parse_with_exp("1250", 2, Rounding::HalfUp)?,
+            UFixValue64::new(13, 2)
+        );
+        assert_eq!(
+            UFixValue8::parse_with_exp("1", -3, Rounding::Floor),
+            Err(ParseFixError::Overflow)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn widening_conversions() {
+        let value = UFixValue8::new(u8::MAX, -2);
+        assert_eq!(UFixValue128::from(value), UFixValue128::new(255, -2));
+        assert_eq!(IFixValue16::from(value), IFixValue16::new(255, -2));
+        assert_eq!(UFixValueSize::from(value), UFixValueSize::new(255, -2));
+        let value = IFixValue32::new(i32::MIN, 5);
+        assert_eq!(
+            IFixValue64::from(value),
+            IFixValue64::new(i32::MIN.into(), 5)
+        );
+    }
+
+    #[test]
+    fn narrowing_conversions() {
+        assert_eq!(
+            UFixValue8::try_from(UFixValue64::new(255, 3)),
+            Ok(UFixValue8::new(255, 3))
+        );
+        assert_eq!(
+            UFixValue8::try_from(UFixValue64::new(256, 3)),
+            Err(FixError::Overflow)
+        );
+        assert_eq!(
+            IFixValue8::try_from(IFixValue64::new(-129, 3)),
+            Err(FixError::Underflow)
+        );
+        assert_eq!(
+            IFixValue128::try_from(UFixValue128::new(u128::MAX, 0)),
+            Err(FixError::Overflow)
+        );
+        assert_eq!(
+            UFixValue64::try_from(UFixValueSize::new(7, -1)),
+            Ok(UFixValue64::new(7, -1))
+        );
+    }
+
+    #[test]
+    fn sign_changes_reject_negatives() {
+        assert_eq!(
+            UFixValue64::try_from(IFixValue64::new(-1, 0)),
+            Err(FixError::NegativeToUnsigned)
+        );
+        assert_eq!(
+            UFixValue128::try_from(IFixValue8::new(i8::MIN, 0)),
+            Err(FixError::NegativeToUnsigned)
+        );
+        assert_eq!(
+            UFixValueSize::try_from(IFixValueSize::new(-5, 2)),
+            Err(FixError::NegativeToUnsigned)
+        );
+        assert_eq!(
+            UFixValue32::try_from(IFixValue64::new(42, -2)),
+            Ok(UFixValue32::new(42, -2))
+        );
+        assert_eq!(
+            IFixValue8::try_from(UFixValue8::new(128, 0)),
+            Err(FixError::Overflow)
+        );
+    }
+
+    #[cfg(feature = "anchor")]
+    #[test]
+    fn space_matches_encoding() -> Result<()> {
+        use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize, InitSpace};
+
+        #[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+        struct Oracle {
+            price: UFixValue64,
+            count: UFixValueSize,
+        }
+
+        assert_eq!(
+            UFixValue64::INIT_SPACE,
+            to_vec(&UFixValue64::default())?.len()
+        );
+        assert_eq!(IFixValue128::INIT_SPACE, 17);
+        assert_eq!(
+            UFixValueSize::INIT_SPACE,
+            to_vec(&UFixValueSize::default())?.len()
+        );
+        assert_eq!(IFixValueSize::INIT_SPACE, 9);
+
+        let oracle = Oracle {
+            price: UFixValue64::new(1, -9),
+            count: UFixValueSize::new(3, 0),
+        };
+        assert_eq!(to_vec(&oracle)?.len(), Oracle::INIT_SPACE);
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_keys() {
+        let updates = [
+            IFixValue64::new(-1_500, -3),
+            IFixValue64::new(-15, -1),
+            IFixValue64::new(2, 0),
+            IFixValue64::new(0, 3),
+            IFixValue64::new(0, -3),
+            IFixValue64::new(2_000, -3),
+        ];
+        let unique: HashSet<_> = updates.into_iter().map(Canonical).collect();
+        assert_eq!(unique.len(), 3);
+        let sorted: BTreeSet<_> = updates.into_iter().map(Canonical).collect();
+        let sorted: Vec<_> = sorted
+            .into_iter()
+            .map(|Canonical(value)| value.normalize())
+            .collect();
+        assert_eq!(
+            sorted,
+            [
+                IFixValue64::new(-15, -1),
+                IFixValue64::new(0, 0),
+                IFixValue64::new(2, 0)
+            ]
+        );
+        assert_eq!(
+            Canonical(IFixValue8::new(-1, 127)).cmp(&Canonical(IFixValue8::new(i8::MIN, -128))),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn signed_arithmetic() -> Result<()> {
+        let a = IFixValue32::new(-15, -1);
+        let b = IFixValue32::new(2, 0);
+        assert_eq!(a.try_add(b)?, IFixValue32::new(5, -1));
+        assert_eq!(a.try_sub(b)?, IFixValue32::new(-35, -1));
+        assert_eq!(a.try_mul(b)?, IFixValue32::new(-30, -1));
+        assert_eq!(a.try_div(b, 0, Rounding::Floor)?, IFixValue32::new(-1, 0));
+        assert_eq!(a.try_div(b, 0, Rounding::HalfUp)?, IFixValue32::new(-1, 0));
+        assert_eq!(
+            a.try_div(b, 0, Rounding::AwayFromZero)?,
+            IFixValue32::new(-1, 0)
+        );
+        assert_eq!(
+            a.try_div(b, 0, Rounding::TowardZero)?,
+            IFixValue32::new(0, 0)
+        );
+        assert_eq!(
+            a.try_div(IFixValue32::new(-2, 0), 1, Rounding::Ceil)?,
+            IFixValue32::new(1, 1)
+        );
+
+        let min = IFixValue8::new(i8::MIN, 0);
+        assert_eq!(
+            min.try_sub(IFixValue8::new(1, -1)),
+            Err(FixError::Underflow)
+        );
+        assert_eq!(min.try_mul(IFixValue8::new(-1, 0)), Err(FixError::Overflow));
+        assert_eq!(min.try_mul(IFixValue8::new(1, 0)), Ok(min));
+        assert_eq!(
+            min.try_div(IFixValue8::new(-1, 0), 0, Rounding::Floor),
+            Err(FixError::Overflow)
+        );
+        assert_eq!(
+            IFixValue8::new(-1, 1).cmp_value(&IFixValue8::new(-1, 0)),
+            Ordering::Less
+        );
+        assert_eq!(min.cmp_value(&IFixValue8::new(-1, 127)), Ordering::Greater);
+        assert_eq!(IFixValue8::new(-1, 127).cmp_value(&min), Ordering::Less);
+        Ok(())
+    }
+
+    #[test]
+    fn div_rounds_across_shifts() -> Result<()> {
+        let two = UFixValue64::new(2, 0);
+        let three = UFixValue64::new(3, 0);
+        assert_eq!(
+            two.try_div(three, -3, Rounding::HalfEven)?,
+            UFixValue64::new(667, -3)
+        );
+        assert_eq!(
+            two.try_div(three, -3, Rounding::Floor)?,
+            UFixValue64::new(666, -3)
+        );
+
+        let ten = UFixValue64::new(1, 1);
+        let tie = UFixValue64::new(50, 0);
+        assert_eq!(
+            tie.try_div(ten, 1, Rounding::HalfEven)?,
+            UFixValue64::new(0, 1)
+        );
+        assert_eq!(
+            tie.try_div(ten, 1, Rounding::HalfUp)?,
+            UFixValue64::new(1, 1)
+        );
+        // 101 ÷ 2 is 0.505 hundreds: the truncated 0.50 is a tie until the remainder counts.
+        let above = UFixValue64::new(101, 0);
+        assert_eq!(
+            above.try_div(two, 2, Rounding::HalfDown)?,
+            UFixValue64::new(1, 2)
+        );
+
+        let tiny = UFixValue64::new(251, 0);
+        assert_eq!(
+            tiny.try_div(ten, 127, Rounding::Ceil)?,
+            UFixValue64::new(1, 127)
+        );
+        assert_eq!(
+            tiny.try_div(ten, 127, Rounding::HalfUp)?,
+            UFixValue64::new(0, 127)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn mul_moves_exponent_into_bits() -> Result<()> {
+        let big = UFixValue64::new(1, 100);
+        assert_eq!(
+            big.try_mul(UFixValue64::new(1, 30))?,
+            UFixValue64::new(1_000, 127)
+        );
+        assert_eq!(
+            big.try_mul(UFixValue64::new(1, 100)),
+            Err(FixError::Overflow)
+        );
+        let small = UFixValue64::new(1, -100);
+        assert_eq!(
+            small.try_mul(UFixValue64::new(1_000, -31))?,
+            UFixValue64::new(1, -128)
+        );
+        assert_eq!(
+            small.try_mul(UFixValue64::new(1, -30)),
+            Err(FixError::PrecisionLoss)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rescale_extreme_exponents() -> Result<()> {
+        let value = UFixValue128::new(u128::MAX, -128);
+        let (fix, rounded) = value.try_into_rescaled::<P127>(Rounding::Ceil)?;
+        assert_eq!((fix.bits, rounded), (1, true));
+        let (fix, rounded) = value.try_into_rescaled::<P127>(Rounding::HalfUp)?;
+        assert_eq!((fix.bits, rounded), (0, true));
+        let (fix, rounded) = UFixValue8::new(0, -128).try_into_rescaled::<P127>(Rounding::Ceil)?;
+        assert_eq!((fix.bits, rounded), (0, false));
+        let (fix, rounded) = UFixValue8::new(0, 127).try_into_rescaled::<N128>(Rounding::Ceil)?;
+        assert_eq!((fix.bits, rounded), (0, false));
+        Ok(())
+    }
+
     fix_value_tests!(U, 8);
     fix_value_tests!(U, 16);
     fix_value_tests!(U, 32);
     fix_value_tests!(U, 64);
     fix_value_tests!(U, 128);
+    fix_value_tests!(U, Size);
     fix_value_tests!(I, 8);
     fix_value_tests!(I, 16);
     fix_value_tests!(I, 32);
     fix_value_tests!(I, 64);
     fix_value_tests!(I, 128);
+    fix_value_tests!(I, Size);
 }
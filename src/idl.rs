@@ -0,0 +1,219 @@
+//! Anchor IDL definitions of decimal `Fix` and `FixValue`.
+//!
+//! A `Fix<Bits, U10, Exp>` is defined as a struct of its `bits`, which matches its Borsh
+//! encoding, named like its alias in [`named`], e.g. `UFix64N9`. The base and exponent are in
+//! the docs of the definition, so client SDKs can scale the bits without reading Rust source.
+//! The zero-copy types of [`pod`] are defined with their `bytemuck` layout.
+//!
+//! [`named`]: crate::aliases::named
+//! [`pod`]: crate::pod
+
+use core::cmp::Ordering;
+
+use anchor_lang::idl::types::{
+    IdlDefinedFields, IdlField, IdlSerialization, IdlType, IdlTypeDef, IdlTypeDefTy,
+};
+use anchor_lang::IdlBuild;
+
+use crate::fix_value::{
+    IFixValue128, IFixValue16, IFixValue32, IFixValue64, IFixValue8, IFixValueSize, UFixValue128,
+    UFixValue16, UFixValue32, UFixValue64, UFixValue8, UFixValueSize,
+};
+use crate::typenum::{Integer, U10};
+use crate::Fix;
+
+/// The typenum name of an exponent, e.g. `N9`, `Z0` or `P3`.
+fn exp_name(exp: i32) -> String {
+    match exp.cmp(&0) {
+        Ordering::Less => format!("N{}", exp.unsigned_abs()),
+        Ordering::Equal => "Z0".into(),
+        Ordering::Greater => format!("P{exp}"),
+    }
+}
+
+fn struct_def(name: String, docs: Vec<String>, fields: Vec<IdlField>) -> IdlTypeDef {
+    IdlTypeDef {
+        name,
+        docs,
+        serialization: IdlSerialization::Borsh,
+        repr: None,
+        generics: vec![],
+        ty: IdlTypeDefTy::Struct {
+            fields: Some(IdlDefinedFields::Named(fields)),
+        },
+    }
+}
+
+fn field(name: &str, ty: IdlType) -> IdlField {
+    IdlField {
+        name: name.into(),
+        docs: vec![],
+        ty,
+    }
+}
+
+// Borsh encodes `usize` and `isize` as 64-bit.
+macro_rules! impl_idl_build {
+    ($bits:ident, $prefix:literal, $idl:ident, $value:ident) => {
+        impl<Exp: Integer> IdlBuild for Fix<$bits, U10, Exp> {
+            fn create_type() -> Option<IdlTypeDef> {
+                let exp = Exp::to_i32();
+                let docs = vec![
+                    format!("Decimal fixed-point number: `bits × 10^{exp}`."),
+                    "base: 10".into(),
+                    format!("exp: {exp}"),
+                ];
+                let fields = vec![field("bits", IdlType::$idl)];
+                Some(struct_def(Self::get_full_path(), docs, fields))
+            }
+
+            fn get_full_path() -> String {
+                format!("{}{}", $prefix, exp_name(Exp::to_i32()))
+            }
+        }
+
+        impl IdlBuild for $value {
+            fn create_type() -> Option<IdlTypeDef> {
+                let docs = vec!["Decimal fixed-point value: `bits × 10^exp`.".into()];
+                let fields = vec![field("bits", IdlType::$idl), field("exp", IdlType::I8)];
+                Some(struct_def(Self::get_full_path(), docs, fields))
+            }
+
+            fn get_full_path() -> String {
+                stringify!($value).into()
+            }
+        }
+    };
+}
+
+impl_idl_build!(u8, "UFix8", U8, UFixValue8);
+impl_idl_build!(u16, "UFix16", U16, UFixValue16);
+impl_idl_build!(u32, "UFix32", U32, UFixValue32);
+impl_idl_build!(u64, "UFix64", U64, UFixValue64);
+impl_idl_build!(u128, "UFix128", U128, UFixValue128);
+impl_idl_build!(usize, "UFixSize", U64, UFixValueSize);
+impl_idl_build!(i8, "IFix8", I8, IFixValue8);
+impl_idl_build!(i16, "IFix16", I16, IFixValue16);
+impl_idl_build!(i32, "IFix32", I32, IFixValue32);
+impl_idl_build!(i64, "IFix64", I64, IFixValue64);
+impl_idl_build!(i128, "IFix128", I128, IFixValue128);
+impl_idl_build!(isize, "IFixSize", I64, IFixValueSize);
+
+#[cfg(feature = "bytemuck")]
+mod pod {
+    use anchor_lang::idl::types::{
+        IdlArrayLen, IdlRepr, IdlReprModifier, IdlSerialization, IdlType, IdlTypeDef,
+    };
+    use anchor_lang::IdlBuild;
+
+    use super::{field, struct_def};
+    use crate::pod::{
+        IFixValue128Pod, IFixValue16Pod, IFixValue32Pod, IFixValue64Pod, IFixValue8Pod,
+        UFixValue128Pod, UFixValue16Pod, UFixValue32Pod, UFixValue64Pod, UFixValue8Pod,
+    };
+
+    macro_rules! impl_idl_build_pod {
+        ($pod:ident, $len:literal) => {
+            impl IdlBuild for $pod {
+                fn create_type() -> Option<IdlTypeDef> {
+                    let docs =
+                        vec!["Decimal fixed-point value: little-endian `bits × 10^exp`.".into()];
+                    let bits = IdlType::Array(Box::new(IdlType::U8), IdlArrayLen::Value($len));
+                    let fields = vec![field("bits", bits), field("exp", IdlType::I8)];
+                    Some(IdlTypeDef {
+                        serialization: IdlSerialization::Bytemuck,
+                        repr: Some(IdlRepr::C(IdlReprModifier {
+                            packed: false,
+                            align: None,
+                        })),
+                        ..struct_def(Self::get_full_path(), docs, fields)
+                    })
+                }
+
+                fn get_full_path() -> String {
+                    stringify!($pod).into()
+                }
+            }
+        };
+    }
+
+    impl_idl_build_pod!(UFixValue8Pod, 1);
+    impl_idl_build_pod!(UFixValue16Pod, 2);
+    impl_idl_build_pod!(UFixValue32Pod, 4);
+    impl_idl_build_pod!(UFixValue64Pod, 8);
+    impl_idl_build_pod!(UFixValue128Pod, 16);
+    impl_idl_build_pod!(IFixValue8Pod, 1);
+    impl_idl_build_pod!(IFixValue16Pod, 2);
+    impl_idl_build_pod!(IFixValue32Pod, 4);
+    impl_idl_build_pod!(IFixValue64Pod, 8);
+    impl_idl_build_pod!(IFixValue128Pod, 16);
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::idl::types::{IdlDefinedFields, IdlType, IdlTypeDefTy};
+    use anchor_lang::IdlBuild;
+
+    use crate::aliases::named::{IFix128P3, UFix64N9, UFixSizeZ0};
+    use crate::fix_value::UFixValue64;
+
+    #[test]
+    fn readable_names() {
+        assert_eq!(UFix64N9::get_full_path(), "UFix64N9");
+        assert_eq!(IFix128P3::get_full_path(), "IFix128P3");
+        assert_eq!(UFixSizeZ0::get_full_path(), "UFixSizeZ0");
+        assert_eq!(UFixValue64::get_full_path(), "UFixValue64");
+    }
+
+    #[test]
+    fn definition_carries_exponent() {
+        let def = UFix64N9::create_type().unwrap();
+        assert_eq!(def.name, "UFix64N9");
+        assert!(def.docs.contains(&"base: 10".to_owned()));
+        assert!(def.docs.contains(&"exp: -9".to_owned()));
+        let IdlTypeDefTy::Struct {
+            fields: Some(IdlDefinedFields::Named(fields)),
+        } = def.ty
+        else {
+            panic!("not a struct with named fields");
+        };
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "bits");
+        assert_eq!(fields[0].ty, IdlType::U64);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn pod_definition_is_bytemuck() {
+        use anchor_lang::idl::types::{IdlArrayLen, IdlSerialization};
+
+        use crate::pod::IFixValue128Pod;
+
+        let def = IFixValue128Pod::create_type().unwrap();
+        assert_eq!(def.name, "IFixValue128Pod");
+        assert_eq!(def.serialization, IdlSerialization::Bytemuck);
+        let IdlTypeDefTy::Struct {
+            fields: Some(IdlDefinedFields::Named(fields)),
+        } = def.ty
+        else {
+            panic!("not a struct with named fields");
+        };
+        assert_eq!(
+            fields[0].ty,
+            IdlType::Array(Box::new(IdlType::U8), IdlArrayLen::Value(16))
+        );
+        assert_eq!(fields[1].ty, IdlType::I8);
+    }
+
+    #[test]
+    fn size_definitions_match_borsh() {
+        let def = UFixSizeZ0::create_type().unwrap();
+        let IdlTypeDefTy::Struct {
+            fields: Some(IdlDefinedFields::Named(fields)),
+        } = def.ty
+        else {
+            panic!("not a struct with named fields");
+        };
+        assert_eq!(fields[0].ty, IdlType::U64);
+    }
+}
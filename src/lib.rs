@@ -31,7 +31,9 @@
 //!
 //! ```
 //! use fix::aliases::si::Centi; // Fix<_, U10, N2>
+//! # #[cfg(not(feature = "strict-checked"))]
 //! assert_eq!(Centi::new(0_30), Centi::new(0_10) + Centi::new(0_20));
+//! assert_eq!(Centi::new(0_10).try_add(Centi::new(0_20)), Ok(Centi::new(0_30)));
 //! ```
 //!
 //! But decimal is inefficient for binary computers, right? Multiplying and dividing by 10 is
@@ -55,14 +57,36 @@
 //!
 //! ```
 //! use fix::aliases::iec::{Gibi, Kibi, Mebi};
+//! # #[cfg(not(feature = "strict-checked"))]
 //! assert_eq!(Mebi::new(3), Gibi::new(6) / Kibi::new(2));
+//! assert_eq!(Gibi::new(6).try_div(Kibi::new(2)), Ok(Mebi::new(3)));
 //! ```
 //!
 //! # `no_std`
 //!
-//! This crate is `no_std`.
-
-//#![no_std]
+//! This crate is `no_std` without its default features, and the core `Fix` type needs no `alloc`.
+//!
+//! # Features
+//!
+//! - `std` (default): `std::error::Error` impls.
+//! - `anchor` (default): Anchor `Space`, error codes and `fix_ok!`. Implies `std` and `borsh`.
+//! - `borsh`: Borsh serialization of `Fix` and `FixValue`, and the self-describing `tagged`
+//!   encoding.
+//! - `serde` (default): Serde serialization of `Fix` and `FixValue`, with the representations in
+//!   `serialize`.
+//! - `bytemuck`: `Pod` and `Zeroable` for `Fix`, and padding-free `FixValue` layouts in `pod`,
+//!   for zero-copy accounts.
+//! - `idl-build`: Anchor IDL definitions of decimal `Fix` and `FixValue`, for the fields named in
+//!   `aliases::named`. Implies `anchor`.
+//! - `typed-floats`: `typed_floats` views of `f64` conversions. Implies `std`.
+//! - `strict-checked`: Removes the arithmetic operators and their `*Assign` forms from `Fix`, which
+//!   panic or wrap on overflow depending on the build profile. The `num_traits` impls that require
+//!   them go too, with `checked_add`, `checked_sub`, `checked_rem`, `saturating_add`,
+//!   `saturating_sub`, `wrapping_add` and `wrapping_sub` kept as inherent methods, and
+//!   `abs_diff` saturates. Arithmetic then goes through the checked, fallible, saturating,
+//!   wrapping and `MulDiv` APIs only.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 pub extern crate muldiv;
 pub extern crate num_traits;
@@ -71,26 +95,51 @@ pub extern crate typed_floats;
 pub extern crate typenum;
 
 pub mod aliases;
+#[cfg(feature = "borsh")]
+mod borsh;
+pub mod display;
+pub mod error;
 pub mod fix_value;
+#[cfg(feature = "idl-build")]
+mod idl;
+pub mod parse;
+#[cfg(feature = "bytemuck")]
+pub mod pod;
 pub mod prelude;
+pub mod rounding;
+#[cfg(feature = "serde")]
+pub mod serialize;
+#[cfg(feature = "borsh")]
+pub mod tagged;
 pub mod util;
 
 use core::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
 use core::fmt::{Debug, Display, Error, Formatter};
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
-use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
-use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+use core::ops::{Add, Div, Mul, Sub};
+#[cfg(not(feature = "strict-checked"))]
+use core::ops::{AddAssign, DivAssign, MulAssign, Neg, Rem, RemAssign, SubAssign};
 
+use error::FixError;
 use muldiv::MulDiv;
+use num_traits::float::FloatCore;
+use num_traits::ops::overflowing::{OverflowingAdd, OverflowingSub};
 use num_traits::{
-    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, ConstZero, SaturatingAdd, SaturatingSub,
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, ConstZero,
+    FromPrimitive, SaturatingAdd, SaturatingSub, ToPrimitive, WrappingAdd, WrappingNeg,
+    WrappingSub, Zero,
 };
+#[cfg(not(feature = "strict-checked"))]
+use num_traits::{Num, One, SaturatingMul};
 use paste::paste;
-use typenum::consts::{U10, Z0};
+use rounding::{MulDivWith, Rounding};
+use typenum::consts::{U10, U2, Z0};
 use typenum::marker_traits::{Bit, Integer, Unsigned};
 use typenum::operator_aliases::{AbsVal, Diff, Le, Sum};
 use typenum::type_operators::{Abs, IsLess};
+#[cfg(not(feature = "strict-checked"))]
+use util::FixExt;
 
 /// Fixed-point number representing _Bits × Base <sup>Exp</sup>_.
 ///
@@ -119,6 +168,22 @@ use typenum::type_operators::{Abs, IsLess};
 /// - _(x B<sup>E</sup>) × y = (x × y) B<sup>E</sup>_
 /// - _(x B<sup>E</sup>) ÷ y = (x ÷ y) B<sup>E</sup>_
 /// - _(x B<sup>E</sup>) % y = (x % y) B<sup>E</sup>_
+///
+/// Like those of _Bits_, the operators panic on overflow in debug builds and wrap in release
+/// builds. The checked, saturating, wrapping and overflowing methods behave the same in both, and
+/// are all that remain with the `strict-checked` feature.
+///
+/// Those named after `num_traits` methods, such as `checked_add`, `saturating_add`, `wrapping_add`
+/// and `overflowing_add`, take their operands by reference like the traits, whether they come
+/// from a trait or not. Those that round, such as `mul_floor` and `wrapping_mul_floor`, take them
+/// by value.
+///
+/// # Layout
+///
+/// `Fix` is `#[repr(transparent)]`: it has the same size, alignment and ABI as _Bits_, whatever
+/// the _Base_ and _Exp_. Slices of _Bits_ and of `Fix` can be cast into each other without copying
+/// with [`Fix::from_bits_slice`] and [`Fix::as_bits_slice`].
+#[repr(transparent)]
 pub struct Fix<Bits, Base, Exp> {
     /// The underlying integer.
     pub bits: Bits,
@@ -185,6 +250,48 @@ impl<Bits, Base, Exp> Fix<Bits, Base, Exp> {
         }
     }
 
+    /// Converts to another _Exp_, like `convert`, but fails instead of overflowing.
+    ///
+    /// Works for any _Base_. Like `convert`, converting to a coarser _Exp_ truncates toward zero,
+    /// whereas `checked_convert` and `try_convert_floor` round down. Converting to a coarser _Exp_
+    /// never fails: if the scale ratio itself does not fit in _Bits_, the truncated result is zero.
+    ///
+    /// # Errors
+    ///
+    /// Only when converting to a finer _Exp_: [`FixError::Overflow`] or [`FixError::Underflow`]
+    /// if the scale ratio or the scaled value does not fit in _Bits_, unless the value is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::aliases::iec::{Kibi, Mebi};
+    /// use fix::aliases::si::{Kilo, Milli};
+    /// assert_eq!(Mebi::new(1u32).try_convert(), Ok(Kibi::new(1024u32)));
+    /// assert!(Kilo::new(5_000u32).try_convert::<fix::typenum::N3>().is_err());
+    /// assert_eq!(Kilo::new(5u32).try_convert(), Ok(Milli::new(5_000_000u32)));
+    /// ```
+    pub fn try_convert<ToExp>(self) -> Result<Fix<Bits, Base, ToExp>, FixError>
+    where
+        Bits: FromUnsigned + CheckedPow + CheckedMul + Div<Output = Bits> + Zero + PartialOrd,
+        Base: Unsigned,
+        Exp: Sub<ToExp>,
+        Diff<Exp, ToExp>: Abs + IsLess<Z0>,
+        AbsVal<Diff<Exp, ToExp>>: Integer,
+    {
+        let base = Bits::from_unsigned::<Base>();
+        let diff = AbsVal::<Diff<Exp, ToExp>>::to_i32();
+        let inverse = Le::<Diff<Exp, ToExp>, Z0>::to_bool();
+        let overflow = FixError::out_of_range(self.bits < Bits::zero());
+
+        match (base.checked_pow(diff.unsigned_abs()), inverse) {
+            _ if self.bits.is_zero() => Ok(Fix::new(self.bits)),
+            (Some(ratio), true) => Ok(Fix::new(self.bits / ratio)),
+            (None, true) => Ok(Fix::new(Bits::zero())),
+            (Some(ratio), false) => self.bits.checked_mul(&ratio).map(Fix::new).ok_or(overflow),
+            (None, false) => Err(overflow),
+        }
+    }
+
     /// Converts the underlying bits to a wider type.
     ///
     /// # Examples
@@ -221,6 +328,100 @@ impl<Bits, Base, Exp> Fix<Bits, Base, Exp> {
     {
         self.bits.try_into().ok().map(Fix::<ToBits, Base, Exp>::new)
     }
+
+    /// Converts underlying bits to a narrower type, like `narrow`, but reports why it fails.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::NegativeToUnsigned`] if a negative value is narrowed to an unsigned type,
+    /// otherwise [`FixError::Overflow`] or [`FixError::Underflow`] if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// assert_eq!(IFix64::<N3>::new(-1).try_narrow::<i8>(), Ok(IFix8::<N3>::new(-1)));
+    /// assert_eq!(IFix64::<N3>::new(-1).try_narrow::<u8>(), Err(FixError::NegativeToUnsigned));
+    /// assert_eq!(IFix64::<N3>::new(-129).try_narrow::<i8>(), Err(FixError::Underflow));
+    /// assert_eq!(IFix64::<N3>::new(256).try_narrow::<u8>(), Err(FixError::Overflow));
+    /// ```
+    pub fn try_narrow<ToBits>(self) -> Result<Fix<ToBits, Base, Exp>, FixError>
+    where
+        Bits: Zero + PartialOrd,
+        ToBits: TryFrom<Bits> + Bounded + Zero + PartialEq,
+    {
+        let negative = self.bits < Bits::zero();
+        self.bits.try_into().map(Fix::new).map_err(|_| {
+            if negative && ToBits::min_value() == ToBits::zero() {
+                FixError::NegativeToUnsigned
+            } else {
+                FixError::out_of_range(negative)
+            }
+        })
+    }
+}
+
+impl<Bits, Base, Exp> Fix<Bits, Base, Exp> {
+    /// Reinterprets a slice of _Bits_ as numbers, without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let raw = [1_500u64, 2_250];
+    /// let prices = UFix64::<N3>::from_bits_slice(&raw);
+    /// assert_eq!(prices, [UFix64::new(1_500), UFix64::new(2_250)]);
+    /// ```
+    pub fn from_bits_slice(bits: &[Bits]) -> &[Self] {
+        // SAFETY: `Fix` is `#[repr(transparent)]` over `Bits`, so the layouts match.
+        unsafe { core::slice::from_raw_parts(bits.as_ptr().cast(), bits.len()) }
+    }
+
+    /// Reinterprets a mutable slice of _Bits_ as numbers, without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let mut raw = [1_500u64, 2_250];
+    /// for price in UFix64::<N3>::from_bits_slice_mut(&mut raw) {
+    ///     *price = price.saturating_add(&UFix64::new(500));
+    /// }
+    /// assert_eq!(raw, [2_000, 2_750]);
+    /// ```
+    pub fn from_bits_slice_mut(bits: &mut [Bits]) -> &mut [Self] {
+        // SAFETY: As above, and the borrow of `bits` is moved into the result.
+        unsafe { core::slice::from_raw_parts_mut(bits.as_mut_ptr().cast(), bits.len()) }
+    }
+
+    /// Reinterprets a slice of numbers as their _Bits_, without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let prices = [UFix64::<N3>::new(1_500), UFix64::new(2_250)];
+    /// assert_eq!(UFix64::as_bits_slice(&prices), [1_500, 2_250]);
+    /// ```
+    pub fn as_bits_slice(fixes: &[Self]) -> &[Bits] {
+        // SAFETY: `Fix` is `#[repr(transparent)]` over `Bits`, so the layouts match.
+        unsafe { core::slice::from_raw_parts(fixes.as_ptr().cast(), fixes.len()) }
+    }
+
+    /// Reinterprets a mutable slice of numbers as their _Bits_, without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let mut prices = [UFix64::<N3>::new(1_500), UFix64::new(2_250)];
+    /// UFix64::as_bits_slice_mut(&mut prices).fill(0);
+    /// assert_eq!(prices, [UFix64::zero(); 2]);
+    /// ```
+    pub fn as_bits_slice_mut(fixes: &mut [Self]) -> &mut [Bits] {
+        // SAFETY: As above, and the borrow of `fixes` is moved into the result.
+        unsafe { core::slice::from_raw_parts_mut(fixes.as_mut_ptr().cast(), fixes.len()) }
+    }
 }
 
 /// Conversion from type-level [`Unsigned`] integers.
@@ -293,6 +494,38 @@ impl_pow!(i64);
 impl_pow!(i128);
 impl_pow!(isize);
 
+/// Checked exponentiation.
+///
+/// Like [`Pow`], but returns `None` instead of overflowing.
+pub trait CheckedPow: Sized {
+    /// Raises `self` to the power of `exp`, or `None` on overflow.
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+}
+
+macro_rules! impl_checked_pow {
+    ($ty:ident) => {
+        impl CheckedPow for $ty {
+            #[inline]
+            fn checked_pow(self, exp: u32) -> Option<Self> {
+                self.checked_pow(exp)
+            }
+        }
+    };
+}
+
+impl_checked_pow!(u8);
+impl_checked_pow!(u16);
+impl_checked_pow!(u32);
+impl_checked_pow!(u64);
+impl_checked_pow!(u128);
+impl_checked_pow!(usize);
+impl_checked_pow!(i8);
+impl_checked_pow!(i16);
+impl_checked_pow!(i32);
+impl_checked_pow!(i64);
+impl_checked_pow!(i128);
+impl_checked_pow!(isize);
+
 // The usual traits.
 
 impl<Bits, Base, Exp> Copy for Fix<Bits, Base, Exp> where Bits: Copy {}
@@ -325,6 +558,96 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base, Exp> Zero for Fix<Bits, Base, Exp>
+where
+    Bits: Zero,
+{
+    fn zero() -> Self {
+        Self::new(Bits::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.bits.is_zero()
+    }
+}
+
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base, Exp> ConstZero for Fix<Bits, Base, Exp>
+where
+    Bits: ConstZero,
+{
+    const ZERO: Self = Self::constant(Bits::ZERO);
+}
+
+/// Only at an _Exp_ of zero, the one precision where `Fix` is closed under `Mul`. Elsewhere, see
+/// [`Fix::one`].
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base> One for Fix<Bits, Base, Z0>
+where
+    Bits: One,
+{
+    fn one() -> Self {
+        Self::new(Bits::one())
+    }
+}
+
+impl<Bits, Base, Exp> Bounded for Fix<Bits, Base, Exp>
+where
+    Bits: Bounded,
+{
+    fn min_value() -> Self {
+        Self::new(Bits::min_value())
+    }
+
+    fn max_value() -> Self {
+        Self::new(Bits::max_value())
+    }
+}
+
+/// Integers at an _Exp_ of zero. `Num` requires closure under all arithmetic operators, which the
+/// other precisions lack.
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base> Num for Fix<Bits, Base, Z0>
+where
+    Bits: Num,
+{
+    type FromStrRadixErr = Bits::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Bits::from_str_radix(s, radix).map(Self::new)
+    }
+}
+
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base> num_traits::Signed for Fix<Bits, Base, Z0>
+where
+    Bits: num_traits::Signed,
+{
+    fn abs(&self) -> Self {
+        Self::new(self.bits.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        Self::new(self.bits.abs_sub(&other.bits))
+    }
+
+    fn signum(&self) -> Self {
+        Self::new(self.bits.signum())
+    }
+
+    fn is_positive(&self) -> bool {
+        self.bits.is_positive()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.bits.is_negative()
+    }
+}
+
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base> num_traits::Unsigned for Fix<Bits, Base, Z0> where Bits: num_traits::Unsigned {}
+
 impl<Bits, Base, Exp> Hash for Fix<Bits, Base, Exp>
 where
     Bits: Hash,
@@ -354,24 +677,17 @@ where
     Exp: Integer,
 {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        let exp = Exp::to_i32();
-        let decimals = usize::try_from(exp.unsigned_abs()).map_err(|_| Error)?;
-        let raw = self.bits.to_string();
-        let (sign, digits) = raw
-            .strip_prefix('-')
-            .map_or(("", raw.as_str()), |d| ("-", d));
-
-        match exp {
-            0.. => write!(f, "{sign}{digits}{}", "0".repeat(decimals)),
-            _ if digits.len() > decimals => {
-                let (integer, fraction) = digits.split_at(digits.len() - decimals);
-                write!(f, "{sign}{integer}.{fraction}")
-            }
-            _ => {
-                let padding = "0".repeat(decimals - digits.len());
-                write!(f, "{sign}0.{padding}{digits}")
-            }
-        }
+        Display::fmt(&self.display_with_rounding(Rounding::HalfEven), f)
+    }
+}
+
+impl<Bits, Exp> Display for Fix<Bits, U2, Exp>
+where
+    Bits: Display,
+    Exp: Integer,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        Display::fmt(&self.display_with_rounding(Rounding::TowardZero), f)
     }
 }
 
@@ -407,6 +723,7 @@ where
 
 // Arithmetic.
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> Neg for Fix<Bits, Base, Exp>
 where
     Bits: Neg<Output = Bits>,
@@ -417,6 +734,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> Add for Fix<Bits, Base, Exp>
 where
     Bits: Add<Output = Bits>,
@@ -427,6 +745,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> Sub for Fix<Bits, Base, Exp>
 where
     Bits: Sub<Output = Bits>,
@@ -437,6 +756,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, LExp, RExp> Mul<Fix<Bits, Base, RExp>> for Fix<Bits, Base, LExp>
 where
     Bits: Mul<Output = Bits>,
@@ -448,6 +768,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, LExp, RExp> Div<Fix<Bits, Base, RExp>> for Fix<Bits, Base, LExp>
 where
     Bits: Div<Output = Bits>,
@@ -459,6 +780,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> Rem for Fix<Bits, Base, Exp>
 where
     Bits: Rem<Output = Bits>,
@@ -469,6 +791,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> Mul<Bits> for Fix<Bits, Base, Exp>
 where
     Bits: Mul<Output = Bits>,
@@ -479,6 +802,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> Div<Bits> for Fix<Bits, Base, Exp>
 where
     Bits: Div<Output = Bits>,
@@ -489,6 +813,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> Rem<Bits> for Fix<Bits, Base, Exp>
 where
     Bits: Rem<Output = Bits>,
@@ -499,6 +824,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> AddAssign for Fix<Bits, Base, Exp>
 where
     Bits: AddAssign,
@@ -508,6 +834,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> SubAssign for Fix<Bits, Base, Exp>
 where
     Bits: SubAssign,
@@ -517,6 +844,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> MulAssign<Bits> for Fix<Bits, Base, Exp>
 where
     Bits: MulAssign,
@@ -526,6 +854,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> DivAssign<Bits> for Fix<Bits, Base, Exp>
 where
     Bits: DivAssign,
@@ -535,6 +864,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, LExp, RExp> RemAssign<Fix<Bits, Base, RExp>> for Fix<Bits, Base, LExp>
 where
     Bits: RemAssign,
@@ -544,6 +874,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> RemAssign<Bits> for Fix<Bits, Base, Exp>
 where
     Bits: RemAssign,
@@ -555,6 +886,7 @@ where
 
 // Checked arithmetic.
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> CheckedAdd for Fix<Bits, Base, Exp>
 where
     Bits: CheckedAdd,
@@ -564,6 +896,7 @@ where
     }
 }
 
+#[cfg(not(feature = "strict-checked"))]
 impl<Bits, Base, Exp> CheckedSub for Fix<Bits, Base, Exp>
 where
     Bits: CheckedSub,
@@ -573,14 +906,120 @@ where
     }
 }
 
+impl<Bits, Base, Exp> CheckedNeg for Fix<Bits, Base, Exp>
+where
+    Bits: CheckedNeg,
+{
+    fn checked_neg(&self) -> Option<Self> {
+        self.bits.checked_neg().map(Self::new)
+    }
+}
+
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base, Exp> CheckedRem for Fix<Bits, Base, Exp>
+where
+    Bits: CheckedRem,
+{
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        self.bits.checked_rem(&v.bits).map(Self::new)
+    }
+}
+
+// Without the operators, `Fix` cannot implement the `num_traits` checked, saturating and wrapping
+// traits, which require them. These take their place, with the same signatures.
+#[cfg(feature = "strict-checked")]
+impl<Bits, Base, Exp> Fix<Bits, Base, Exp> {
+    /// Adds `v`, returning `None` on overflow.
+    pub fn checked_add(&self, v: &Self) -> Option<Self>
+    where
+        Bits: CheckedAdd,
+    {
+        self.bits.checked_add(&v.bits).map(Self::new)
+    }
+
+    /// Subtracts `v`, returning `None` on overflow.
+    pub fn checked_sub(&self, v: &Self) -> Option<Self>
+    where
+        Bits: CheckedSub,
+    {
+        self.bits.checked_sub(&v.bits).map(Self::new)
+    }
+
+    /// Calculates the remainder of dividing by `v`, returning `None` if `v` is zero or on
+    /// overflow.
+    pub fn checked_rem(&self, v: &Self) -> Option<Self>
+    where
+        Bits: CheckedRem,
+    {
+        self.bits.checked_rem(&v.bits).map(Self::new)
+    }
+
+    /// Adds `v`, saturating at the bounds of _Bits_.
+    #[must_use]
+    pub fn saturating_add(&self, v: &Self) -> Self
+    where
+        Bits: SaturatingAdd,
+    {
+        Self::new(self.bits.saturating_add(&v.bits))
+    }
+
+    /// Subtracts `v`, saturating at the bounds of _Bits_.
+    #[must_use]
+    pub fn saturating_sub(&self, v: &Self) -> Self
+    where
+        Bits: SaturatingSub,
+    {
+        Self::new(self.bits.saturating_sub(&v.bits))
+    }
+
+    /// Adds `v`, wrapping around at the bounds of _Bits_.
+    #[must_use]
+    pub fn wrapping_add(&self, v: &Self) -> Self
+    where
+        Bits: WrappingAdd,
+    {
+        Self::new(self.bits.wrapping_add(&v.bits))
+    }
+
+    /// Subtracts `v`, wrapping around at the bounds of _Bits_.
+    #[must_use]
+    pub fn wrapping_sub(&self, v: &Self) -> Self
+    where
+        Bits: WrappingSub,
+    {
+        Self::new(self.bits.wrapping_sub(&v.bits))
+    }
+}
+
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base, Exp> Fix<Bits, Base, Exp>
+where
+    Bits: CheckedSub + Copy,
+{
+    #[must_use]
+    pub fn abs_diff(&self, v: &Self) -> Fix<Bits, Base, Exp> {
+        Self::new(
+            self.bits
+                .checked_sub(&v.bits)
+                .unwrap_or_else(|| v.bits - self.bits),
+        )
+    }
+}
+
+#[cfg(feature = "strict-checked")]
 impl<Bits, Base, Exp> Fix<Bits, Base, Exp>
 where
-    Self: CheckedSub,
-    Bits: Copy,
+    Bits: SaturatingSub + PartialOrd + Copy,
 {
+    /// The distance to `v`, saturating at the bounds of _Bits_, which that of signed numbers can
+    /// exceed.
     #[must_use]
     pub fn abs_diff(&self, v: &Self) -> Fix<Bits, Base, Exp> {
-        self.checked_sub(v).unwrap_or_else(|| *v - *self)
+        if self.bits < v.bits {
+            v.saturating_sub(self)
+        } else {
+            self.saturating_sub(v)
+        }
     }
 }
 
@@ -652,95 +1091,703 @@ where
     }
 }
 
-// Saturating arithmetic.
-
-impl<Bits, Base, Exp> SaturatingAdd for Fix<Bits, Base, Exp>
+impl<Bits, Base, LExp, RExp> MulDivWith<Fix<Bits, Base, RExp>> for Fix<Bits, Base, LExp>
 where
-    Bits: SaturatingAdd,
+    Bits: MulDivWith,
 {
-    fn saturating_add(&self, v: &Self) -> Self {
-        Self::new(self.bits.saturating_add(&v.bits))
+    type Output = Fix<<Bits as MulDivWith>::Output, Base, LExp>;
+    fn mul_div_with(
+        self,
+        num: Fix<Bits, Base, RExp>,
+        denom: Fix<Bits, Base, RExp>,
+        rounding: Rounding,
+    ) -> Option<Self::Output> {
+        self.bits
+            .mul_div_with(num.bits, denom.bits, rounding)
+            .map(Self::Output::new)
     }
 }
 
-impl<Bits, Base, Exp> SaturatingSub for Fix<Bits, Base, Exp>
+// Fallible arithmetic.
+
+impl<Bits, Base, Exp> Fix<Bits, Base, Exp>
 where
-    Bits: SaturatingSub,
+    Bits: Copy + Zero + PartialOrd,
 {
-    fn saturating_sub(&self, v: &Self) -> Self {
-        Self::new(self.bits.saturating_sub(&v.bits))
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use num_traits::{SaturatingAdd, SaturatingSub};
-    use typenum::{N3, P3, Z0};
-
-    use crate::aliases::decimal::{IFix64, UFix64};
-    use crate::aliases::si::{Kilo, Micro, Milli, Nano, Unit};
-    use crate::{CheckedAdd, CheckedDivFix, CheckedMulFix, CheckedSub, MulDiv};
-
-    #[test]
-    fn convert_milli_to_kilo() {
-        assert_eq!(Kilo::new(15), Milli::new(15_000_000).convert());
+    /// Adds `rhs`, like `checked_add`, but reports which way it overflows.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::Overflow`] or [`FixError::Underflow`] if the sum does not fit in _Bits_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let max = IFix8::<N1>::new(i8::MAX);
+    /// assert_eq!(max.try_add(IFix8::new(-1)), Ok(IFix8::new(126)));
+    /// assert_eq!(max.try_add(IFix8::new(1)), Err(FixError::Overflow));
+    /// ```
+    pub fn try_add(self, rhs: Self) -> Result<Self, FixError>
+    where
+        Bits: CheckedAdd,
+    {
+        self.checked_add(&rhs)
+            .ok_or_else(|| FixError::out_of_range(rhs.bits < Bits::zero()))
     }
 
-    #[test]
-    fn convert_kilo_to_milli() {
-        assert_eq!(Milli::new(15_000_000), Kilo::new(15).convert());
+    /// Subtracts `rhs`, like `checked_sub`, but reports which way it overflows.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::Overflow`] or [`FixError::Underflow`] if the difference does not fit in _Bits_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let one = UFix64::<N3>::new(1_000);
+    /// assert_eq!(one.try_sub(UFix64::new(250)), Ok(UFix64::new(750)));
+    /// assert_eq!(one.try_sub(UFix64::new(1_001)), Err(FixError::Underflow));
+    /// ```
+    pub fn try_sub(self, rhs: Self) -> Result<Self, FixError>
+    where
+        Bits: CheckedSub,
+    {
+        self.checked_sub(&rhs)
+            .ok_or_else(|| FixError::out_of_range(rhs.bits > Bits::zero()))
     }
 
-    #[test]
-    fn cmp() {
-        assert!(Kilo::new(1) < Kilo::new(2));
+    /// Multiplies by `rhs`, like `checked_mul`, but reports which way it overflows.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::Overflow`] or [`FixError::Underflow`] if the product does not fit in _Bits_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::aliases::si::{Kilo, Milli, Unit};
+    /// use fix::error::FixError;
+    /// assert_eq!(Kilo::new(3i16).try_mul(Milli::new(-5)), Ok(Unit::new(-15)));
+    /// assert_eq!(Kilo::new(300i16).try_mul(Milli::new(-500)), Err(FixError::Underflow));
+    /// ```
+    pub fn try_mul<RExp>(
+        self,
+        rhs: Fix<Bits, Base, RExp>,
+    ) -> Result<Fix<Bits, Base, Sum<Exp, RExp>>, FixError>
+    where
+        Bits: CheckedMul,
+        Exp: Add<RExp>,
+    {
+        let negative = (self.bits < Bits::zero()) != (rhs.bits < Bits::zero());
+        CheckedMulFix::checked_mul(&self, &rhs).ok_or(FixError::out_of_range(negative))
     }
 
+    /// Divides by `rhs`, like `checked_div`, but reports why it fails.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::DivisionByZero`] if `rhs` is zero, otherwise [`FixError::Overflow`] if the
+    /// quotient does not fit in _Bits_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::aliases::si::{Kilo, Milli, Mega};
+    /// use fix::error::FixError;
+    /// assert_eq!(Kilo::new(15).try_div(Milli::new(5)), Ok(Mega::new(3)));
+    /// assert_eq!(Kilo::new(15).try_div(Milli::new(0)), Err(FixError::DivisionByZero));
+    /// ```
+    pub fn try_div<RExp>(
+        self,
+        rhs: Fix<Bits, Base, RExp>,
+    ) -> Result<Fix<Bits, Base, Diff<Exp, RExp>>, FixError>
+    where
+        Bits: CheckedDiv,
+        Exp: Sub<RExp>,
+    {
+        if rhs.bits.is_zero() {
+            return Err(FixError::DivisionByZero);
+        }
+        let negative = (self.bits < Bits::zero()) != (rhs.bits < Bits::zero());
+        CheckedDivFix::checked_div(&self, &rhs).ok_or(FixError::out_of_range(negative))
+    }
+
+    /// Calculates `floor(self × num ÷ denom)`, like `mul_div_floor`, but reports why it fails
+    /// instead of panicking on a zero `denom`.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::DivisionByZero`] if `denom` is zero, otherwise [`FixError::Overflow`] or
+    /// [`FixError::Underflow`] if the result does not fit in _Bits_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let x = UFix64::<N3>::new(10_000);
+    /// let (num, denom) = (UFix64::<N2>::new(1), UFix64::<N2>::new(3));
+    /// assert_eq!(x.try_mul_div_floor(num, denom), Ok(UFix64::new(3_333)));
+    /// assert_eq!(x.try_mul_div_floor(num, UFix64::new(0)), Err(FixError::DivisionByZero));
+    /// ```
+    pub fn try_mul_div_floor<RExp>(
+        self,
+        num: Fix<Bits, Base, RExp>,
+        denom: Fix<Bits, Base, RExp>,
+    ) -> Result<Self, FixError>
+    where
+        Bits: MulDiv<Output = Bits>,
+    {
+        self.try_mul_div(num, denom, MulDiv::mul_div_floor)
+    }
+
+    /// Calculates `ceil(self × num ÷ denom)`, like `mul_div_ceil`, but reports why it fails
+    /// instead of panicking on a zero `denom`.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::DivisionByZero`] if `denom` is zero, otherwise [`FixError::Overflow`] or
+    /// [`FixError::Underflow`] if the result does not fit in _Bits_.
+    pub fn try_mul_div_ceil<RExp>(
+        self,
+        num: Fix<Bits, Base, RExp>,
+        denom: Fix<Bits, Base, RExp>,
+    ) -> Result<Self, FixError>
+    where
+        Bits: MulDiv<Output = Bits>,
+    {
+        self.try_mul_div(num, denom, MulDiv::mul_div_ceil)
+    }
+
+    /// Calculates `round(self × num ÷ denom)`, like `mul_div_round`, but reports why it fails
+    /// instead of panicking on a zero `denom`.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::DivisionByZero`] if `denom` is zero, otherwise [`FixError::Overflow`] or
+    /// [`FixError::Underflow`] if the result does not fit in _Bits_.
+    pub fn try_mul_div_round<RExp>(
+        self,
+        num: Fix<Bits, Base, RExp>,
+        denom: Fix<Bits, Base, RExp>,
+    ) -> Result<Self, FixError>
+    where
+        Bits: MulDiv<Output = Bits>,
+    {
+        self.try_mul_div(num, denom, MulDiv::mul_div_round)
+    }
+
+    fn try_mul_div<RExp>(
+        self,
+        num: Fix<Bits, Base, RExp>,
+        denom: Fix<Bits, Base, RExp>,
+        mul_div: impl FnOnce(Bits, Bits, Bits) -> Option<Bits>,
+    ) -> Result<Self, FixError> {
+        if denom.bits.is_zero() {
+            return Err(FixError::DivisionByZero);
+        }
+        let zero = Bits::zero();
+        let negative = ((self.bits < zero) != (num.bits < zero)) != (denom.bits < zero);
+        mul_div(self.bits, num.bits, denom.bits)
+            .map(Self::new)
+            .ok_or(FixError::out_of_range(negative))
+    }
+}
+
+// Saturating arithmetic.
+
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base, Exp> SaturatingAdd for Fix<Bits, Base, Exp>
+where
+    Bits: SaturatingAdd,
+{
+    fn saturating_add(&self, v: &Self) -> Self {
+        Self::new(self.bits.saturating_add(&v.bits))
+    }
+}
+
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base, Exp> SaturatingSub for Fix<Bits, Base, Exp>
+where
+    Bits: SaturatingSub,
+{
+    fn saturating_sub(&self, v: &Self) -> Self {
+        Self::new(self.bits.saturating_sub(&v.bits))
+    }
+}
+
+/// Rescales like [`Fix::saturating_mul_floor`]. `SaturatingMul` requires `Mul` to keep the _Exp_,
+/// so it only applies at an _Exp_ of zero; use the inherent method elsewhere.
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base, Exp> SaturatingMul for Fix<Bits, Base, Exp>
+where
+    Self: FixExt + Mul<Output = Self>,
+    Bits: MulDiv<Output = Bits> + Bounded + Copy + Zero + PartialOrd,
+{
+    fn saturating_mul(&self, v: &Self) -> Self {
+        self.saturating_mul_floor(*v)
+    }
+}
+
+// Wrapping arithmetic.
+
+impl<Bits, Base, Exp> Fix<Bits, Base, Exp> {
+    /// Adds `v`, returning the wrapped sum and whether it overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let max = IFix8::<N1>::new(i8::MAX);
+    /// assert_eq!(max.overflowing_add(&IFix8::new(-1)), (IFix8::new(126), false));
+    /// assert_eq!(max.overflowing_add(&IFix8::new(1)), (IFix8::new(i8::MIN), true));
+    /// ```
+    pub fn overflowing_add(&self, v: &Self) -> (Self, bool)
+    where
+        Bits: OverflowingAdd,
+    {
+        let (bits, overflowed) = self.bits.overflowing_add(&v.bits);
+        (Self::new(bits), overflowed)
+    }
+
+    /// Subtracts `v`, returning the wrapped difference and whether it overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let min = IFix8::<N1>::new(i8::MIN);
+    /// assert_eq!(min.overflowing_sub(&IFix8::new(1)), (IFix8::new(i8::MAX), true));
+    /// ```
+    pub fn overflowing_sub(&self, v: &Self) -> (Self, bool)
+    where
+        Bits: OverflowingSub,
+    {
+        let (bits, overflowed) = self.bits.overflowing_sub(&v.bits);
+        (Self::new(bits), overflowed)
+    }
+}
+
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base, Exp> WrappingAdd for Fix<Bits, Base, Exp>
+where
+    Bits: WrappingAdd,
+{
+    fn wrapping_add(&self, v: &Self) -> Self {
+        Self::new(self.bits.wrapping_add(&v.bits))
+    }
+}
+
+#[cfg(not(feature = "strict-checked"))]
+impl<Bits, Base, Exp> WrappingSub for Fix<Bits, Base, Exp>
+where
+    Bits: WrappingSub,
+{
+    fn wrapping_sub(&self, v: &Self) -> Self {
+        Self::new(self.bits.wrapping_sub(&v.bits))
+    }
+}
+
+impl<Bits, Base, Exp> WrappingNeg for Fix<Bits, Base, Exp>
+where
+    Bits: WrappingNeg,
+{
+    fn wrapping_neg(&self) -> Self {
+        Self::new(self.bits.wrapping_neg())
+    }
+}
+
+// Primitive conversions.
+
+impl<Bits, Base, Exp> Fix<Bits, Base, Exp>
+where
+    Base: Unsigned,
+    Exp: Integer,
+{
+    /// _Base<sup>|Exp|</sup>_, or `None` if it overflows `u128`.
+    fn scale() -> Option<u128> {
+        Base::U128.checked_pow(Exp::to_i32().unsigned_abs())
+    }
+
+    /// _Base<sup>|Exp|</sup>_ as a float, to scale by in floating point.
+    fn float_scale() -> f64 {
+        FloatCore::powi(f64::from(Base::U32), Exp::to_i32().saturating_abs())
+    }
+
+    /// Sign and magnitude of the integer part, truncated toward zero.
+    fn integer_part(&self) -> Option<(bool, u128)>
+    where
+        Bits: ToPrimitive,
+    {
+        let (negative, magnitude) = match self.bits.to_i128() {
+            Some(bits) => (bits < 0, bits.unsigned_abs()),
+            None => (false, self.bits.to_u128()?),
+        };
+        let magnitude = match Self::scale() {
+            _ if magnitude == 0 => 0,
+            scale if Exp::to_i32() < 0 => {
+                scale.map_or(Some(0), |scale| magnitude.checked_div(scale))?
+            }
+            scale => magnitude.checked_mul(scale?)?,
+        };
+        Some((negative, magnitude))
+    }
+
+    /// Magnitude of the _Bits_ of an integer, if exactly representable.
+    fn bits_of_integer(magnitude: u128) -> Option<u128> {
+        match Self::scale() {
+            _ if magnitude == 0 => Some(0),
+            scale if Exp::to_i32() <= 0 => magnitude.checked_mul(scale?),
+            scale => {
+                let scale = scale?;
+                // `is_multiple_of` is too new for older Solana toolchains.
+                #[allow(clippy::manual_is_multiple_of)]
+                let exact = magnitude % scale == 0;
+                exact.then(|| magnitude / scale)
+            }
+        }
+    }
+}
+
+/// Converts the value, not the _Bits_. Integers are truncated toward zero, like casting a float.
+///
+/// # Examples
+///
+/// ```
+/// use fix::num_traits::ToPrimitive;
+/// use fix::prelude::*;
+/// let x = IFix64::<N3>::new(-2_750);
+/// assert_eq!(x.to_i32(), Some(-2));
+/// assert_eq!(x.to_u32(), None);
+/// assert_eq!(ToPrimitive::to_f64(&x), Some(-2.75));
+/// ```
+impl<Bits, Base, Exp> ToPrimitive for Fix<Bits, Base, Exp>
+where
+    Bits: ToPrimitive,
+    Base: Unsigned,
+    Exp: Integer,
+{
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128()?.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128()?.to_u64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        match self.integer_part()? {
+            (true, magnitude) => 0i128.checked_sub_unsigned(magnitude),
+            (false, magnitude) => i128::try_from(magnitude).ok(),
+        }
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        match self.integer_part()? {
+            (true, 1..) => None,
+            (_, magnitude) => Some(magnitude),
+        }
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        let bits = self.bits.to_f64()?;
+        if Exp::to_i32() < 0 {
+            Some(bits / Self::float_scale())
+        } else {
+            Some(bits * Self::float_scale())
+        }
+    }
+}
+
+/// Converts to the value, not the _Bits_. Integers must be exactly representable, while floats
+/// are truncated toward zero to the precision, like casting a float to an integer. With a
+/// _Base_ of 10, a float is read as its shortest decimal form, so `0.29` converts to exactly
+/// 0.29 rather than to the binary fraction just below it.
+///
+/// # Examples
+///
+/// ```
+/// use fix::aliases::si::Kilo;
+/// use fix::num_traits::FromPrimitive;
+/// use fix::prelude::*;
+/// assert_eq!(IFix64::<N3>::from_i32(-2), Some(IFix64::new(-2_000)));
+/// assert_eq!(Kilo::<u64>::from_u32(1_500), None);
+/// assert_eq!(IFix64::<N3>::from_f64(-2.7509), Some(IFix64::new(-2_750)));
+/// assert_eq!(UFix64::<N2>::from_f64(0.29), Some(UFix64::new(29)));
+/// assert_eq!(UFix8::<N1>::from_f64(25.6), None);
+/// ```
+impl<Bits, Base, Exp> FromPrimitive for Fix<Bits, Base, Exp>
+where
+    Bits: FromPrimitive + Copy + Zero + CheckedAdd + CheckedSub + CheckedMul,
+    Base: Unsigned,
+    Exp: Integer,
+{
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::from_i128(n.into())
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::from_u128(n.into())
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        let magnitude = Self::bits_of_integer(n.unsigned_abs())?;
+        let bits = if n < 0 {
+            Bits::from_i128(0i128.checked_sub_unsigned(magnitude)?)
+        } else {
+            Bits::from_u128(magnitude)
+        };
+        bits.map(Self::new)
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Bits::from_u128(Self::bits_of_integer(n)?).map(Self::new)
+    }
+
+    fn from_f32(n: f32) -> Option<Self> {
+        if Base::U32 == 10 {
+            parse::float_to_bits(n, Exp::to_i32()).map(Self::new)
+        } else {
+            Self::from_f64(n.into())
+        }
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        // Scaling by a power of ten is inexact in binary floating point.
+        if Base::U32 == 10 {
+            return parse::float_to_bits(n, Exp::to_i32()).map(Self::new);
+        }
+        let bits = if Exp::to_i32() < 0 {
+            n * Self::float_scale()
+        } else {
+            n / Self::float_scale()
+        };
+        Bits::from_f64(bits).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::{Bounded, CheckedNeg, FromPrimitive, ToPrimitive, WrappingNeg};
+    #[cfg(not(feature = "strict-checked"))]
+    use num_traits::{
+        CheckedRem, Num, One, SaturatingAdd, SaturatingMul, SaturatingSub, Signed, WrappingAdd,
+        WrappingSub, Zero,
+    };
+    use typenum::{N1, N1000, N18, N2, N24, N3, N32, N4, N6, N64, N8, P10, P1000, P3, P64, Z0};
+
+    use crate::aliases::binary;
+    use crate::aliases::decimal::{
+        IFix128, IFix16, IFix32, IFix64, IFix8, IFixSize, UFix128, UFix16, UFix32, UFix64, UFix8,
+        UFixSize,
+    };
+    use crate::aliases::iec::{Kibi, Mebi};
+    use crate::aliases::si::{Deci, Kilo, Micro, Milli, Nano, Unit};
+    use crate::error::FixError;
+    use crate::rounding::Rounding;
+    #[cfg(not(feature = "strict-checked"))]
+    use crate::{CheckedAdd, CheckedSub};
+    use crate::{CheckedDivFix, CheckedMulFix, MulDiv};
+
+    #[test]
+    fn convert_milli_to_kilo() {
+        assert_eq!(Kilo::new(15), Milli::new(15_000_000).convert());
+    }
+
+    #[test]
+    fn convert_kilo_to_milli() {
+        assert_eq!(Milli::new(15_000_000), Kilo::new(15).convert());
+    }
+
+    #[test]
+    fn try_convert_matches_convert() {
+        assert_eq!(Milli::new(15_000_000).try_convert(), Ok(Kilo::new(15)));
+        assert_eq!(Kilo::new(15).try_convert(), Ok(Milli::new(15_000_000)));
+        assert_eq!(Kilo::new(-15).try_convert(), Ok(Milli::new(-15_000_000)));
+    }
+
+    #[test]
+    fn try_convert_binary() {
+        assert_eq!(Mebi::new(3u16).try_convert(), Ok(Kibi::new(3_072u16)));
+        assert_eq!(Kibi::new(3_072u16).try_convert(), Ok(Mebi::new(3u16)));
+        assert_eq!(
+            Mebi::new(64u16).try_convert::<P10>(),
+            Err(FixError::Overflow)
+        );
+    }
+
+    #[test]
+    fn try_convert_overflow_in_multiply() {
+        assert_eq!(Unit::new(26u8).try_convert::<N1>(), Err(FixError::Overflow));
+        assert_eq!(Unit::new(25u8).try_convert::<N1>(), Ok(Deci::new(250u8)));
+        assert_eq!(
+            Unit::new(-13i8).try_convert::<N1>(),
+            Err(FixError::Underflow)
+        );
+    }
+
+    #[test]
+    fn try_convert_overflow_in_ratio() {
+        assert_eq!(Unit::new(0u8).try_convert::<N3>(), Ok(Milli::new(0u8)));
+        assert_eq!(Unit::new(1u8).try_convert::<N3>(), Err(FixError::Overflow));
+        assert_eq!(Milli::new(255u8).try_convert::<Z0>(), Ok(Unit::new(0u8)));
+        assert_eq!(Nano::new(i8::MIN).try_convert::<Z0>(), Ok(Unit::new(0i8)));
+    }
+
+    #[test]
+    fn try_add_and_sub_report_direction() {
+        let min = IFix8::<N1>::new(i8::MIN);
+        assert_eq!(min.try_add(IFix8::new(-1)), Err(FixError::Underflow));
+        assert_eq!(min.try_sub(IFix8::new(-1)), Ok(IFix8::new(-127)));
+        assert_eq!(min.try_sub(IFix8::new(1)), Err(FixError::Underflow));
+        assert_eq!(
+            IFix8::<N1>::new(i8::MAX).try_sub(IFix8::new(-1)),
+            Err(FixError::Overflow)
+        );
+    }
+
+    #[test]
+    fn try_mul_and_div() {
+        assert_eq!(Kilo::new(-3i8).try_mul(Milli::new(40)), Ok(Unit::new(-120)));
+        assert_eq!(
+            Kilo::new(-3i8).try_mul(Milli::new(-50)),
+            Err(FixError::Overflow)
+        );
+        assert_eq!(
+            Kilo::new(i8::MIN).try_div(Milli::new(-1)),
+            Err(FixError::Overflow)
+        );
+        assert_eq!(
+            Kilo::new(5u8).try_div(Milli::new(0)),
+            Err(FixError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn try_mul_div_reports_direction() {
+        let x = IFix64::<N3>::new(i64::MAX);
+        let (two, one) = (IFix64::<N3>::new(2), IFix64::<N3>::new(1));
+        assert_eq!(x.try_mul_div_floor(two, one), Err(FixError::Overflow));
+        let (minus_two, minus_one) = (IFix64::<N3>::new(-2), IFix64::<N3>::new(-1));
+        assert_eq!(x.try_mul_div_ceil(two, minus_one), Err(FixError::Underflow));
+        assert_eq!(
+            x.try_mul_div_round(minus_two, minus_one),
+            Err(FixError::Overflow)
+        );
+        assert_eq!(
+            x.try_mul_div_round(one, two),
+            Ok(IFix64::new(i64::MAX / 2 + 1))
+        );
+        assert_eq!(
+            x.try_mul_div_ceil(one, IFix64::<N3>::new(0)),
+            Err(FixError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn try_narrow_reports_cause() {
+        assert_eq!(
+            Milli::new(-1i64).try_narrow::<u32>(),
+            Err(FixError::NegativeToUnsigned)
+        );
+        assert_eq!(
+            Milli::new(i64::MIN).try_narrow::<i32>(),
+            Err(FixError::Underflow)
+        );
+        assert_eq!(
+            Milli::new(u128::MAX).try_narrow::<u64>(),
+            Err(FixError::Overflow)
+        );
+        assert_eq!(Milli::new(7u128).try_narrow::<u8>(), Ok(Milli::new(7u8)));
+    }
+
+    #[test]
+    fn layout_is_transparent() {
+        use core::mem::{align_of, size_of};
+        assert_eq!(size_of::<UFix8<N3>>(), size_of::<u8>());
+        assert_eq!(size_of::<IFix128<P10>>(), size_of::<i128>());
+        assert_eq!(align_of::<IFix128<P10>>(), align_of::<i128>());
+        assert_eq!(size_of::<binary::UFix64<N32>>(), size_of::<u64>());
+    }
+
+    #[test]
+    fn bits_slice_casts() {
+        let mut raw = [1i64, -2, i64::MAX];
+        let fixes = IFix64::<N6>::from_bits_slice(&raw);
+        assert_eq!(fixes.len(), 3);
+        assert_eq!(fixes[1], IFix64::new(-2));
+        assert_eq!(IFix64::as_bits_slice(fixes), [1, -2, i64::MAX]);
+
+        IFix64::<N6>::from_bits_slice_mut(&mut raw)[0] = IFix64::new(7);
+        assert_eq!(raw[0], 7);
+
+        let mut fixes = [IFix64::<N6>::new(3); 2];
+        IFix64::as_bits_slice_mut(&mut fixes)[1] = -3;
+        assert_eq!(fixes, [IFix64::new(3), IFix64::new(-3)]);
+        assert!(IFix64::<N6>::from_bits_slice(&[]).is_empty());
+    }
+
+    #[test]
+    fn cmp() {
+        assert!(Kilo::new(1) < Kilo::new(2));
+    }
+
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn neg() {
         assert_eq!(Kilo::new(-1), -Kilo::new(1i32));
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn add() {
         assert_eq!(Kilo::new(3), Kilo::new(1) + Kilo::new(2));
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn sub() {
         assert_eq!(Kilo::new(1), Kilo::new(3) - Kilo::new(2));
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn mul() {
         assert_eq!(Unit::new(6), Kilo::new(2) * Milli::new(3));
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn div() {
         assert_eq!(Unit::new(3), Kilo::new(6) / Kilo::new(2));
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn rem() {
         assert_eq!(Kilo::new(1), Kilo::new(6) % Kilo::new(5));
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn mul_bits() {
         assert_eq!(Kilo::new(6), Kilo::new(2) * 3);
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn div_bits() {
         assert_eq!(Kilo::new(3), Kilo::new(6) / 2);
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn rem_bits() {
         assert_eq!(Kilo::new(1), Kilo::new(6) % 5);
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn add_assign() {
         let mut a = Kilo::new(1);
@@ -748,6 +1795,7 @@ mod tests {
         assert_eq!(Kilo::new(3), a);
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn sub_assign() {
         let mut a = Kilo::new(3);
@@ -755,6 +1803,7 @@ mod tests {
         assert_eq!(Kilo::new(1), a);
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn mul_assign_bits() {
         let mut a = Kilo::new(2);
@@ -762,6 +1811,7 @@ mod tests {
         assert_eq!(Kilo::new(6), a);
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn div_assign_bits() {
         let mut a = Kilo::new(6);
@@ -769,6 +1819,7 @@ mod tests {
         assert_eq!(Kilo::new(3), a);
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn rem_assign() {
         let mut a = Kilo::new(6);
@@ -776,6 +1827,7 @@ mod tests {
         assert_eq!(Kilo::new(1), a);
     }
 
+    #[cfg(not(feature = "strict-checked"))]
     #[test]
     fn rem_assign_bits() {
         let mut a = Kilo::new(6);
@@ -925,6 +1977,18 @@ mod tests {
         assert_eq!(start.abs_diff(&end), end);
     }
 
+    #[cfg(feature = "strict-checked")]
+    #[test]
+    fn abs_diff_saturates() {
+        let min = IFix64::<N3>::new(i64::MIN);
+        assert_eq!(min.abs_diff(&IFix64::new(1)), IFix64::new(i64::MAX));
+        assert_eq!(IFix64::new(1).abs_diff(&min), IFix64::new(i64::MAX));
+        assert_eq!(
+            IFix64::<N3>::new(-5).abs_diff(&IFix64::new(2)),
+            IFix64::new(7)
+        );
+    }
+
     #[test]
     fn constant() {
         assert_eq!(Kilo::constant(69u64), Kilo::new(69u64));
@@ -944,6 +2008,153 @@ mod tests {
         assert_eq!(max, result);
     }
 
+    #[test]
+    fn saturating_mul_rescales() {
+        let x = IFix64::<N3>::new(1_500);
+        assert_eq!(x.saturating_mul_floor(x), IFix64::new(2_250));
+        assert_eq!(
+            IFix64::<N3>::new(-1).saturating_mul_floor(IFix64::new(1)),
+            IFix64::new(-1)
+        );
+        assert_eq!(
+            IFix64::<N3>::new(i64::MIN).saturating_mul_floor(IFix64::new(-1_000)),
+            IFix64::new(i64::MAX)
+        );
+        assert_eq!(
+            UFix8::<N1>::new(u8::MAX).saturating_mul_floor(UFix8::new(20)),
+            UFix8::new(u8::MAX)
+        );
+    }
+
+    #[test]
+    fn wrapping() {
+        let max = Milli::new(u8::MAX);
+        assert_eq!(max.wrapping_add(&Milli::new(2)), Milli::new(1));
+        assert_eq!(Milli::new(0u8).wrapping_sub(&Milli::new(1)), max);
+        assert_eq!(Milli::new(i8::MIN).wrapping_neg(), Milli::new(i8::MIN));
+    }
+
+    #[test]
+    fn overflowing() {
+        let max = Milli::new(u8::MAX);
+        assert_eq!(max.overflowing_add(&Milli::new(2)), (Milli::new(1), true));
+        assert_eq!(max.overflowing_add(&Milli::new(0)), (max, false));
+        assert_eq!(Milli::new(0u8).overflowing_sub(&Milli::new(1)), (max, true));
+        assert_eq!(
+            Milli::new(-1i64).overflowing_sub(&Milli::new(i64::MAX)),
+            (Milli::new(i64::MIN), false)
+        );
+    }
+
+    #[test]
+    fn bounded() {
+        assert_eq!(<Milli<i8> as Bounded>::min_value(), Milli::new(i8::MIN));
+        assert_eq!(<Milli<u8> as Bounded>::max_value(), Milli::new(u8::MAX));
+    }
+
+    #[test]
+    fn checked_neg_and_rem() {
+        assert_eq!(Milli::new(i8::MIN).checked_neg(), None);
+        assert_eq!(Milli::new(5i8).checked_neg(), Some(Milli::new(-5)));
+        assert_eq!(Milli::new(7u8).checked_rem(&Milli::new(0)), None);
+        assert_eq!(
+            Milli::new(7u8).checked_rem(&Milli::new(3)),
+            Some(Milli::new(1))
+        );
+    }
+
+    #[cfg(not(feature = "strict-checked"))]
+    #[test]
+    fn num_traits_identities() {
+        fn sum<T: Zero + Copy>(values: &[T]) -> T {
+            values.iter().fold(T::zero(), |acc, &x| acc + x)
+        }
+        fn sign<T: Signed>(x: &T) -> T {
+            x.signum()
+        }
+
+        assert_eq!(sum(&[Milli::new(1u64), Milli::new(2)]), Milli::new(3));
+        assert!(<Milli<u64> as Zero>::zero().is_zero());
+        assert_eq!(<Unit<i32> as One>::one(), Unit::one());
+        assert_eq!(sign(&Unit::new(-7i64)), Unit::new(-1));
+        assert_eq!(Unit::<i64>::from_str_radix("-ff", 16), Ok(Unit::new(-255)));
+        assert_eq!(
+            Unit::new(i64::MIN).saturating_mul(&Unit::new(-1)),
+            Unit::new(i64::MAX)
+        );
+        assert_eq!(
+            Unit::new(u8::MAX).saturating_mul(&Unit::new(2)),
+            Unit::new(u8::MAX)
+        );
+    }
+
+    #[test]
+    fn to_primitive_truncates() {
+        assert_eq!(Milli::new(2_999i64).to_i8(), Some(2));
+        assert_eq!(Milli::new(-2_999i64).to_i8(), Some(-2));
+        assert_eq!(Milli::new(-999i64).to_u8(), Some(0));
+        assert_eq!(Milli::new(-1_000i64).to_u8(), None);
+        assert_eq!(Kilo::new(3u8).to_u16(), Some(3_000));
+        assert_eq!(Kilo::new(3u8).to_u8(), None);
+        assert_eq!(Milli::new(u128::MAX).to_u128(), Some(u128::MAX / 1_000));
+        assert_eq!(Milli::new(i128::MIN).to_i128(), Some(i128::MIN / 1_000));
+        assert_eq!(Unit::new(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!(IFix128::<N64>::new(i128::MAX).to_i64(), Some(0));
+        assert_eq!(UFix8::<P64>::new(0).to_u8(), Some(0));
+        assert_eq!(UFix8::<P64>::new(1).to_u128(), None);
+        assert_eq!(
+            ToPrimitive::to_f64(&binary::IFix32::<N2>::new(-3)),
+            Some(-0.75)
+        );
+        assert_eq!(ToPrimitive::to_f64(&Kilo::new(-3i32)), Some(-3_000.0));
+    }
+
+    #[test]
+    fn from_primitive_is_exact_for_integers() {
+        assert_eq!(Milli::<i16>::from_i8(-7), Some(Milli::new(-7_000)));
+        assert_eq!(Milli::<i16>::from_i8(-70), None);
+        assert_eq!(Milli::<u16>::from_i8(-1), None);
+        assert_eq!(Kilo::<i32>::from_i64(-3_000), Some(Kilo::new(-3)));
+        assert_eq!(Kilo::<i32>::from_i64(-3_001), None);
+        assert_eq!(Kilo::<u64>::from_u64(0), Some(Kilo::new(0)));
+        assert_eq!(UFix8::<P64>::from_u8(1), None);
+        assert_eq!(
+            Unit::<i128>::from_i128(i128::MIN),
+            Some(Unit::new(i128::MIN))
+        );
+        assert_eq!(
+            Unit::<u128>::from_u128(u128::MAX),
+            Some(Unit::new(u128::MAX))
+        );
+    }
+
+    #[test]
+    fn from_primitive_truncates_floats() {
+        assert_eq!(Milli::<i32>::from_f64(-1.2345), Some(Milli::new(-1_234)));
+        assert_eq!(Milli::<u32>::from_f64(-0.0009), Some(Milli::new(0)));
+        assert_eq!(Milli::<u32>::from_f64(-1.0), None);
+        assert_eq!(Kilo::<u32>::from_f32(2_999.0), Some(Kilo::new(2)));
+        assert_eq!(Milli::<i64>::from_f64(f64::NAN), None);
+        assert_eq!(
+            binary::UFix8::<N2>::from_f64(1.75),
+            Some(binary::UFix8::new(7))
+        );
+    }
+
+    #[test]
+    fn from_primitive_reads_floats_as_written() {
+        assert_eq!(UFix64::<N2>::from_f64(0.29), Some(UFix64::new(29)));
+        assert_eq!(UFix64::<N3>::from_f64(0.29), Some(UFix64::new(290)));
+        assert_eq!(UFix64::<N2>::from_f64(4.35), Some(UFix64::new(435)));
+        assert_eq!(UFix64::<N3>::from_f64(4.35), Some(UFix64::new(4_350)));
+        assert_eq!(UFix64::<N2>::from_f64(1.005), Some(UFix64::new(100)));
+        assert_eq!(UFix64::<N3>::from_f64(1.005), Some(UFix64::new(1_005)));
+        assert_eq!(IFix64::<N2>::from_f32(-0.29), Some(IFix64::new(-29)));
+        assert_eq!(UFix64::<N2>::from_f64(f64::INFINITY), None);
+        assert_eq!(UFix64::<N2>::from_f64(1e300), None);
+        assert_eq!(UFix64::<N2>::from_f64(5e-324), Some(UFix64::new(0)));
+    }
+
     #[test]
     fn zero_is_zero() {
         assert_eq!(Kilo::<u64>::zero().bits, 0);
@@ -1047,4 +2258,175 @@ mod tests {
     fn display_zero_exp() {
         assert_eq!(UFix64::<Z0>::new(42).to_string(), "42");
     }
+
+    #[test]
+    fn display_width_and_alignment() {
+        let x = IFix64::<N3>::new(-1_234);
+        assert_eq!(format!("{x:10}"), "    -1.234");
+        assert_eq!(format!("{x:<10}"), "-1.234    ");
+        assert_eq!(format!("{x:^10}"), "  -1.234  ");
+        assert_eq!(format!("{x:*>10}"), "****-1.234");
+        assert_eq!(format!("{x:010}"), "-00001.234");
+        assert_eq!(format!("{x:3}"), "-1.234");
+    }
+
+    #[test]
+    fn display_sign_plus() {
+        assert_eq!(format!("{:+}", UFix64::<N3>::new(1)), "+0.001");
+        assert_eq!(format!("{:+}", IFix64::<N3>::new(-1)), "-0.001");
+        assert_eq!(format!("{:+08}", UFix64::<P3>::new(5)), "+0005000");
+    }
+
+    #[test]
+    fn display_extremes() {
+        assert_eq!(
+            IFix128::<N18>::new(i128::MIN).to_string(),
+            "-170141183460469231731.687303715884105728"
+        );
+        assert_eq!(
+            UFix128::<N24>::new(u128::MAX).to_string(),
+            "340282366920938.463463374607431768211455"
+        );
+        assert_eq!(IFix8::<N6>::new(i8::MIN).to_string(), "-0.000128");
+        assert_eq!(UFix8::<P3>::new(u8::MAX).to_string(), "255000");
+    }
+
+    #[test]
+    fn display_precision_pads() {
+        assert_eq!(format!("{:.5}", UFix64::<N3>::new(1_500)), "1.50000");
+        assert_eq!(format!("{:.3}", UFix64::<N3>::new(1_500)), "1.500");
+        assert_eq!(format!("{:.2}", UFix64::<P3>::new(5)), "5000.00");
+        assert_eq!(format!("{:.1}", UFix64::<Z0>::new(42)), "42.0");
+    }
+
+    #[test]
+    fn display_precision_rounds_half_even() {
+        assert_eq!(format!("{:.2}", UFix64::<N6>::new(1_234_567)), "1.23");
+        assert_eq!(format!("{:.2}", UFix64::<N3>::new(1_235)), "1.24");
+        assert_eq!(format!("{:.2}", UFix64::<N3>::new(1_245)), "1.24");
+        assert_eq!(format!("{:.2}", UFix64::<N4>::new(12_451)), "1.25");
+        assert_eq!(format!("{:.0}", UFix64::<N1>::new(25)), "2");
+        assert_eq!(format!("{:.0}", UFix64::<N1>::new(35)), "4");
+    }
+
+    #[test]
+    fn display_precision_carries() {
+        assert_eq!(format!("{:.2}", UFix64::<N3>::new(9_999)), "10.00");
+        assert_eq!(format!("{:.2}", IFix64::<N3>::new(-9_999)), "-10.00");
+        assert_eq!(format!("{:.0}", UFix64::<N3>::new(999)), "1");
+        assert_eq!(format!("{:.1}", UFix64::<N6>::new(60)), "0.0");
+        assert_eq!(format!("{:.1}", UFix64::<N3>::new(60)), "0.1");
+        assert_eq!(format!("{:.2}", IFix64::<N3>::new(-1)), "-0.00");
+        assert_eq!(
+            format!("{:.0}", UFix64::<N2>::new(u64::MAX)),
+            "184467440737095516"
+        );
+        assert_eq!(
+            format!("{:.0}", IFix128::<N1>::new(i128::MIN)),
+            "-17014118346046923173168730371588410573"
+        );
+    }
+
+    #[test]
+    fn display_precision_with_padding() {
+        let x = IFix64::<N6>::new(-1_234_567);
+        assert_eq!(format!("{x:>8.2}"), "   -1.23");
+        assert_eq!(format!("{x:08.2}"), "-0001.23");
+        assert_eq!(format!("{x:+.1}"), "-1.2");
+    }
+
+    #[test]
+    fn display_with_rounding_modes() {
+        let x = IFix64::<N3>::new(-1_245);
+        let rounded = |rounding| format!("{:.2}", x.display_with_rounding(rounding));
+        assert_eq!(rounded(Rounding::TowardZero), "-1.24");
+        assert_eq!(rounded(Rounding::AwayFromZero), "-1.25");
+        assert_eq!(rounded(Rounding::Floor), "-1.25");
+        assert_eq!(rounded(Rounding::Ceil), "-1.24");
+        assert_eq!(rounded(Rounding::HalfUp), "-1.25");
+        assert_eq!(rounded(Rounding::HalfDown), "-1.24");
+        assert_eq!(rounded(Rounding::HalfEven), "-1.24");
+        assert_eq!(
+            x.display_with_rounding(Rounding::Floor).to_string(),
+            "-1.245"
+        );
+    }
+
+    #[test]
+    fn display_binary_fraction() {
+        assert_eq!(binary::UFix8::<N1>::new(3).to_string(), "1.5");
+        assert_eq!(binary::UFix8::<N3>::new(1).to_string(), "0.125");
+        assert_eq!(binary::IFix16::<N4>::new(-24).to_string(), "-1.5000");
+        assert_eq!(binary::UFix32::<N8>::new(0).to_string(), "0.00000000");
+        assert_eq!(
+            binary::UFix64::<N32>::new(u64::MAX).to_string(),
+            "4294967295.99999999976716935634613037109375"
+        );
+    }
+
+    #[test]
+    fn display_binary_integer() {
+        assert_eq!(Mebi::new(3u32).to_string(), "3145728");
+        assert_eq!(binary::IFix8::<Z0>::new(-7).to_string(), "-7");
+        assert_eq!(
+            binary::UFix128::<P64>::new(u128::MAX).to_string(),
+            "6277101735386680763835789423207666416083908700390324961280"
+        );
+    }
+
+    #[test]
+    fn display_binary_precision_truncates() {
+        let x = binary::IFix16::<N8>::new(-383);
+        assert_eq!(x.to_string(), "-1.49609375");
+        assert_eq!(format!("{x:.2}"), "-1.49");
+        assert_eq!(format!("{x:.0}"), "-1");
+        assert_eq!(format!("{x:.10}"), "-1.4960937500");
+        assert_eq!(
+            format!("{:.2}", x.display_with_rounding(Rounding::HalfEven)),
+            "-1.50"
+        );
+        assert_eq!(format!("{x:>12.4}"), "     -1.4960");
+    }
+
+    #[test]
+    fn display_binary_long_expansion() {
+        let x = binary::UFix128::<N1000>::new(u128::MAX).to_string();
+        let (integer, fraction) = x.split_once('.').unwrap();
+        assert_eq!(integer, "0");
+        assert_eq!(fraction.len(), 1000);
+        assert!(fraction.starts_with(&"0".repeat(262)));
+        assert!(fraction.ends_with("49609375"));
+        assert_eq!(
+            format!("{:.300}", binary::UFix128::<N1000>::new(u128::MAX)),
+            format!("0.{}", &fraction[..300])
+        );
+        assert_eq!(
+            format!("{:.262}", binary::IFix8::<N1000>::new(-1)),
+            format!("-0.{}", "0".repeat(262))
+        );
+        assert_eq!(binary::UFix8::<P1000>::new(1).to_string().len(), 302);
+    }
+
+    #[test]
+    fn display_wide_bits() {
+        struct Wide;
+        impl core::fmt::Display for Wide {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "-1{}", "0".repeat(59))
+            }
+        }
+        let x = crate::Fix::<Wide, typenum::U10, N3>::new(Wide);
+        assert_eq!(x.to_string(), format!("-1{}.000", "0".repeat(56)));
+        assert_eq!(format!("{x:.1}"), format!("-1{}.0", "0".repeat(56)));
+    }
+
+    #[test]
+    fn display_all_bits() {
+        assert_eq!(UFix16::<N1>::new(12).to_string(), "1.2");
+        assert_eq!(UFix32::<N1>::new(12).to_string(), "1.2");
+        assert_eq!(UFixSize::<N1>::new(12).to_string(), "1.2");
+        assert_eq!(IFix16::<N1>::new(-12).to_string(), "-1.2");
+        assert_eq!(IFix32::<N1>::new(-12).to_string(), "-1.2");
+        assert_eq!(IFixSize::<N1>::new(-12).to_string(), "-1.2");
+    }
 }
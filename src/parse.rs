@@ -0,0 +1,469 @@
+use core::fmt::{self, Display, Formatter, LowerExp, Write};
+use core::iter;
+use core::str::FromStr;
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, Zero};
+
+use crate::rounding::{Discarded, Rounding};
+use crate::typenum::{Integer, U10};
+use crate::Fix;
+
+/// Error parsing a decimal `Fix` from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseFixError {
+    /// The input is empty.
+    Empty,
+    /// The input contains a character that is not part of a decimal number.
+    InvalidDigit,
+    /// The input has more significant fractional digits than _Exp_ allows.
+    TooManyDecimals,
+    /// The value does not fit in _Bits_.
+    Overflow,
+    /// The input is a valid number, but not in the form written by `Display`.
+    NonCanonical,
+}
+
+impl Display for ParseFixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "cannot parse `Fix` from empty string",
+            Self::InvalidDigit => "invalid digit found in string",
+            Self::TooManyDecimals => "too many decimals for `Fix` exponent",
+            Self::Overflow => "number too large to fit in `Fix` bits",
+            Self::NonCanonical => "number is not in canonical `Fix` form",
+        })
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+impl std::error::Error for ParseFixError {}
+
+/// A decimal number borrowed from its textual form: _digits × 10<sup>exp</sup>_, where the
+/// digits are `integer` followed by `fraction`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Decimal<'a> {
+    negative: bool,
+    integer: &'a str,
+    fraction: &'a str,
+    exp: i64,
+}
+
+impl<'a> Decimal<'a> {
+    /// Splits `s` into sign, digits and exponent, accepting `[+-]digits[.digits][(e|E)[+-]digits]`.
+    /// Either the integer or fractional digits may be omitted, but not both.
+    pub(crate) fn parse(s: &'a str) -> Result<Self, ParseFixError> {
+        if s.is_empty() {
+            return Err(ParseFixError::Empty);
+        }
+        let (negative, unsigned) = match s.as_bytes()[0] {
+            b'-' => (true, &s[1..]),
+            b'+' => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (mantissa, exp) = match unsigned.split_once(['e', 'E']) {
+            Some((mantissa, exp)) => (mantissa, parse_exp(exp)?),
+            None => (unsigned, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if (integer.is_empty() && fraction.is_empty())
+            || !is_digits(integer)
+            || !is_digits(fraction)
+        {
+            return Err(ParseFixError::InvalidDigit);
+        }
+        let scale = i64::try_from(fraction.len()).map_err(|_| ParseFixError::InvalidDigit)?;
+        Ok(Decimal {
+            negative,
+            integer,
+            fraction,
+            exp: exp.saturating_sub(scale),
+        })
+    }
+
+    /// The exponent of the last written digit, e.g. −3 for `"1.500"` and 2 for `"15e2"`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn exp(&self) -> i64 {
+        self.exp
+    }
+
+    /// The exponent of the last nonzero digit, e.g. −1 for `"1.500"` and 2 for `"15e2"`, or 0 for
+    /// zero: the coarsest that holds the value exactly.
+    pub(crate) fn significant_exp(&self) -> i64 {
+        let len = self.integer.len() + self.fraction.len();
+        let digits = self.integer.bytes().chain(self.fraction.bytes());
+        match digits.rev().take_while(|&b| b == b'0').count() {
+            zeros if zeros == len => 0,
+            zeros => self
+                .exp
+                .saturating_add(i64::try_from(zeros).unwrap_or(i64::MAX)),
+        }
+    }
+
+    /// Scales to a multiple of 10<sup>`exp`</sup>, failing with `TooManyDecimals` on any
+    /// precision loss when `rounding` is `None`.
+    pub(crate) fn to_bits<Bits>(
+        self,
+        exp: i32,
+        rounding: Option<Rounding>,
+    ) -> Result<Bits, ParseFixError>
+    where
+        Bits: Copy + Zero + FromPrimitive + CheckedAdd + CheckedSub + CheckedMul,
+    {
+        let len = self.integer.len() + self.fraction.len();
+        let shift = self.exp.saturating_sub(i64::from(exp));
+        let dropped = usize::try_from(shift.min(0).unsigned_abs()).unwrap_or(usize::MAX);
+        let mut digits = self
+            .integer
+            .bytes()
+            .chain(self.fraction.bytes())
+            .map(|b| b - b'0');
+
+        let mut bits = Bits::zero();
+        let mut odd = false;
+        for digit in digits.by_ref().take(len.saturating_sub(dropped)) {
+            bits = self.push(bits, digit, 10)?;
+            odd = digit % 2 == 1;
+        }
+
+        // Digits dropped beyond the input are implicit leading zeros of the discarded part.
+        let implicit = iter::once(0).take(usize::from(dropped > len));
+        let discarded = Discarded::of_digits(implicit.chain(digits));
+        if discarded != Discarded::Zero {
+            let rounding = rounding.ok_or(ParseFixError::TooManyDecimals)?;
+            if rounding.bumps(self.negative, odd, discarded) {
+                bits = self.push(bits, 1, 1)?;
+            }
+        }
+
+        if !bits.is_zero() {
+            for _ in 0..shift.max(0) {
+                bits = self.push(bits, 0, 10)?;
+            }
+        }
+        Ok(bits)
+    }
+
+    /// Computes `bits × radix ± digit`, moving away from zero in the direction of the sign.
+    fn push<Bits>(self, bits: Bits, digit: u8, radix: u8) -> Result<Bits, ParseFixError>
+    where
+        Bits: Copy + FromPrimitive + CheckedAdd + CheckedSub + CheckedMul,
+    {
+        let radix = Bits::from_u8(radix).ok_or(ParseFixError::Overflow)?;
+        let digit = Bits::from_u8(digit).ok_or(ParseFixError::Overflow)?;
+        let scaled = bits.checked_mul(&radix).ok_or(ParseFixError::Overflow)?;
+        if self.negative {
+            scaled.checked_sub(&digit)
+        } else {
+            scaled.checked_add(&digit)
+        }
+        .ok_or(ParseFixError::Overflow)
+    }
+}
+
+/// Parses a scientific-notation exponent, saturating far beyond any representable scale.
+fn parse_exp(s: &str) -> Result<i64, ParseFixError> {
+    let (negative, digits) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseFixError::InvalidDigit);
+    }
+    let magnitude = digits.bytes().fold(0i64, |exp, b| {
+        exp.saturating_mul(10).saturating_add(i64::from(b - b'0'))
+    });
+    Ok(if negative { -magnitude } else { magnitude })
+}
+
+/// Saturates an exponent to `i8`, the nearest that still holds the value if its digits allow.
+pub(crate) fn saturate_exp(exp: i64) -> i8 {
+    i8::try_from(exp).unwrap_or(if exp < 0 { i8::MIN } else { i8::MAX })
+}
+
+/// Collects formatted output on the stack, failing if it does not fit.
+struct Buffer<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Write for Buffer<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Converts a float to the _Bits_ of 10<sup>`exp`</sup> through its shortest decimal form, so
+/// that `0.29` is read as written rather than as the binary fraction just below it. Further
+/// digits are truncated.
+pub(crate) fn float_to_bits<Bits>(n: impl LowerExp, exp: i32) -> Option<Bits>
+where
+    Bits: Copy + Zero + FromPrimitive + CheckedAdd + CheckedSub + CheckedMul,
+{
+    // Longer than any `f64` in scientific notation, such as `-2.2250738585072014e-308`.
+    let mut buffer = Buffer {
+        buf: [0; 32],
+        len: 0,
+    };
+    write!(buffer, "{n:e}").ok()?;
+    let s = core::str::from_utf8(&buffer.buf[..buffer.len]).ok()?;
+    let decimal = Decimal::parse(s).ok()?;
+    decimal.to_bits(exp, Some(Rounding::TowardZero)).ok()
+}
+
+/// Compares formatted output against an expected string without allocating.
+struct Expect<'a>(&'a str);
+
+impl Write for Expect<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 = self.0.strip_prefix(s).ok_or(fmt::Error)?;
+        Ok(())
+    }
+}
+
+/// Parses decimal notation with an optional sign and scientific exponent, such as `"1.5"`,
+/// `"-0.001"` or `"15e-1"`. Leading and trailing zeros are accepted; any other digit beyond
+/// the precision of _Exp_ fails with [`ParseFixError::TooManyDecimals`].
+///
+/// # Examples
+///
+/// ```
+/// use fix::prelude::*;
+/// assert_eq!("1.5".parse(), Ok(UFix64::<N3>::new(1_500)));
+/// assert_eq!("-25e-3".parse(), Ok(IFix64::<N3>::new(-25)));
+/// assert_eq!("1.2345".parse::<UFix64<N3>>(), Err(ParseFixError::TooManyDecimals));
+/// ```
+impl<Bits, Exp> FromStr for Fix<Bits, U10, Exp>
+where
+    Bits: Copy + Zero + FromPrimitive + CheckedAdd + CheckedSub + CheckedMul,
+    Exp: Integer,
+{
+    type Err = ParseFixError;
+    fn from_str(s: &str) -> Result<Self, ParseFixError> {
+        Decimal::parse(s)?
+            .to_bits(Exp::to_i32(), None)
+            .map(Self::new)
+    }
+}
+
+impl<Bits, Exp> Fix<Bits, U10, Exp>
+where
+    Bits: Copy + Zero + FromPrimitive + CheckedAdd + CheckedSub + CheckedMul,
+    Exp: Integer,
+{
+    /// Like `FromStr`, but rounds digits beyond the precision of _Exp_.
+    ///
+    /// # Errors
+    ///
+    /// Fails on malformed input, or if the rounded value does not fit in _Bits_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let half_even = UFix64::<N2>::from_str_rounded("0.125", Rounding::HalfEven);
+    /// assert_eq!(half_even, Ok(UFix64::<N2>::new(12)));
+    /// let floor = IFix64::<N2>::from_str_rounded("-0.121", Rounding::Floor);
+    /// assert_eq!(floor, Ok(IFix64::<N2>::new(-13)));
+    /// ```
+    pub fn from_str_rounded(s: &str, rounding: Rounding) -> Result<Self, ParseFixError> {
+        Decimal::parse(s)?
+            .to_bits(Exp::to_i32(), Some(rounding))
+            .map(Self::new)
+    }
+
+    /// Like `FromStr`, but accepts only the exact text written by `Display`.
+    ///
+    /// # Errors
+    ///
+    /// Fails like `FromStr`, or with [`ParseFixError::NonCanonical`] if formatting the value
+    /// back would not reproduce `s`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// assert_eq!(UFix64::<N3>::from_str_strict("1.500"), Ok(UFix64::<N3>::new(1_500)));
+    /// assert_eq!(UFix64::<N3>::from_str_strict("1.5"), Err(ParseFixError::NonCanonical));
+    /// ```
+    pub fn from_str_strict(s: &str) -> Result<Self, ParseFixError>
+    where
+        Self: Display,
+    {
+        let value: Self = s.parse()?;
+        let mut expect = Expect(s);
+        if write!(expect, "{value}").is_ok() && expect.0.is_empty() {
+            Ok(value)
+        } else {
+            Err(ParseFixError::NonCanonical)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typenum::{N2, N3, N6, P3, Z0};
+
+    use super::ParseFixError;
+    use crate::aliases::decimal::{IFix64, IFix8, UFix64, UFix8};
+    use crate::rounding::Rounding;
+
+    #[test]
+    fn parse_decimal() {
+        assert_eq!("1.234".parse(), Ok(UFix64::<N3>::new(1_234)));
+        assert_eq!("0.001".parse(), Ok(UFix64::<N3>::new(1)));
+        assert_eq!("42".parse(), Ok(UFix64::<N3>::new(42_000)));
+        assert_eq!(".5".parse(), Ok(UFix64::<N3>::new(500)));
+        assert_eq!("5.".parse(), Ok(UFix64::<N3>::new(5_000)));
+    }
+
+    #[test]
+    fn parse_signs() {
+        assert_eq!("-1.234".parse(), Ok(IFix64::<N3>::new(-1_234)));
+        assert_eq!("+1.234".parse(), Ok(IFix64::<N3>::new(1_234)));
+        assert_eq!("-0".parse(), Ok(UFix64::<N3>::new(0)));
+        assert_eq!("-1".parse::<UFix64<N3>>(), Err(ParseFixError::Overflow));
+    }
+
+    #[test]
+    fn parse_leading_and_trailing_zeros() {
+        assert_eq!("0001.2000000".parse(), Ok(UFix64::<N3>::new(1_200)));
+        assert_eq!("-000.000".parse(), Ok(IFix64::<N3>::new(0)));
+    }
+
+    #[test]
+    fn parse_scientific() {
+        assert_eq!("1.5e3".parse(), Ok(UFix64::<Z0>::new(1_500)));
+        assert_eq!("15E-4".parse(), Ok(UFix64::<N6>::new(1_500)));
+        assert_eq!("2e+3".parse(), Ok(UFix64::<P3>::new(2)));
+        assert_eq!("0e99999999999999999999".parse(), Ok(UFix64::<N3>::new(0)));
+        assert_eq!(
+            "1e-99999999999999999999".parse::<UFix64<N3>>(),
+            Err(ParseFixError::TooManyDecimals)
+        );
+    }
+
+    #[test]
+    fn parse_positive_exp() {
+        assert_eq!("5000".parse(), Ok(UFix64::<P3>::new(5)));
+        assert_eq!(
+            "5500".parse::<UFix64<P3>>(),
+            Err(ParseFixError::TooManyDecimals)
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!("".parse::<UFix64<N3>>(), Err(ParseFixError::Empty));
+        for s in [
+            "-", ".", "1.2.3", "1,5", " 1", "1e", "e5", "0x10", "1.5e1.5", "--1",
+        ] {
+            assert_eq!(
+                s.parse::<UFix64<N3>>(),
+                Err(ParseFixError::InvalidDigit),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_too_many_decimals() {
+        assert_eq!(
+            "1.2345".parse::<UFix64<N3>>(),
+            Err(ParseFixError::TooManyDecimals)
+        );
+        assert_eq!(
+            "0.0001".parse::<UFix64<N3>>(),
+            Err(ParseFixError::TooManyDecimals)
+        );
+    }
+
+    #[test]
+    fn parse_overflow() {
+        assert_eq!("255".parse(), Ok(UFix8::<Z0>::new(255)));
+        assert_eq!("256".parse::<UFix8<Z0>>(), Err(ParseFixError::Overflow));
+        assert_eq!("-1.28".parse(), Ok(IFix8::<N2>::new(i8::MIN)));
+        assert_eq!("1.28".parse::<IFix8<N2>>(), Err(ParseFixError::Overflow));
+        assert_eq!("1e3".parse::<UFix8<Z0>>(), Err(ParseFixError::Overflow));
+    }
+
+    #[test]
+    fn parse_rounded() {
+        let parse = |s, rounding| IFix64::<N2>::from_str_rounded(s, rounding).map(|x| x.bits);
+        assert_eq!(parse("1.125", Rounding::Floor), Ok(112));
+        assert_eq!(parse("-1.125", Rounding::Floor), Ok(-113));
+        assert_eq!(parse("1.121", Rounding::Ceil), Ok(113));
+        assert_eq!(parse("-1.129", Rounding::TowardZero), Ok(-112));
+        assert_eq!(parse("-1.121", Rounding::AwayFromZero), Ok(-113));
+        assert_eq!(parse("-1.125", Rounding::HalfUp), Ok(-113));
+        assert_eq!(parse("1.125", Rounding::HalfDown), Ok(112));
+        assert_eq!(parse("1.1251", Rounding::HalfDown), Ok(113));
+        assert_eq!(parse("1.125", Rounding::HalfEven), Ok(112));
+        assert_eq!(parse("1.135", Rounding::HalfEven), Ok(114));
+        assert_eq!(parse("0.004", Rounding::Ceil), Ok(1));
+        assert_eq!(parse("0.00001", Rounding::HalfUp), Ok(0));
+        assert_eq!(parse("1e-9", Rounding::Ceil), Ok(1));
+    }
+
+    #[test]
+    fn parse_rounded_overflow() {
+        assert_eq!(
+            UFix8::<N2>::from_str_rounded("2.555", Rounding::Ceil),
+            Err(ParseFixError::Overflow)
+        );
+        assert_eq!(
+            UFix8::<N2>::from_str_rounded("-0.001", Rounding::Floor),
+            Err(ParseFixError::Overflow)
+        );
+        assert_eq!(
+            UFix8::<N2>::from_str_rounded("-0.001", Rounding::Ceil),
+            Ok(UFix8::<N2>::new(0))
+        );
+    }
+
+    #[test]
+    fn parse_strict() {
+        assert_eq!(
+            IFix64::<N3>::from_str_strict("-1.500"),
+            Ok(IFix64::<N3>::new(-1_500))
+        );
+        for s in ["1.5", "+1.500", "01.500", "1500e-3", "-0.000"] {
+            assert_eq!(
+                IFix64::<N3>::from_str_strict(s),
+                Err(ParseFixError::NonCanonical),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for _ in 0..1_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let unsigned = UFix64::<N6>::new(state);
+            let signed = IFix64::<N3>::new(state.cast_signed());
+            assert_eq!(
+                UFix64::<N6>::from_str_strict(&unsigned.to_string()),
+                Ok(unsigned)
+            );
+            assert_eq!(
+                IFix64::<N3>::from_str_strict(&signed.to_string()),
+                Ok(signed)
+            );
+        }
+        for bits in [i64::MIN, -1, 0, 1, i64::MAX] {
+            let value = IFix64::<N6>::new(bits);
+            assert_eq!(value.to_string().parse(), Ok(value));
+        }
+    }
+}
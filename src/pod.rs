@@ -0,0 +1,182 @@
+//! Zero-copy layouts for `bytemuck`, as required by Anchor's `#[account(zero_copy)]`.
+//!
+//! `Fix` is `#[repr(transparent)]` over its _Bits_, so it is [`Pod`] wherever they are and can
+//! be stored in zero-copy structs and arrays as is.
+//!
+//! A `FixValue` pairs wide bits with an `i8` exponent, which leaves padding, so it cannot be
+//! `Pod`. Each `FixValue` type here has a `Pod` counterpart, such as [`UFixValue64Pod`], which
+//! stores the bits as little-endian bytes followed by the exponent. It has no padding and an
+//! alignment of 1, so arrays of it are packed, and its bytes are those of the Borsh encoding.
+//! There are none for `usize` and `isize`, whose width depends on the target.
+//!
+//! # Examples
+//!
+//! ```
+//! use fix::pod::UFixValue64Pod;
+//! use fix::prelude::*;
+//! let prices = [UFixValue64Pod::new(1_500, -3); 4];
+//! let bytes: &[u8] = bytemuck::cast_slice(&prices);
+//! assert_eq!(bytes.len(), 4 * 9);
+//! assert_eq!(UFixValue64::from(prices[0]), UFixValue64::new(1_500, -3));
+//! ```
+
+use core::fmt;
+use core::mem::{align_of, size_of};
+
+use bytemuck::{Pod, Zeroable};
+use paste::paste;
+
+use crate::fix_value::{
+    IFixValue128, IFixValue16, IFixValue32, IFixValue64, IFixValue8, UFixValue128, UFixValue16,
+    UFixValue32, UFixValue64, UFixValue8,
+};
+use crate::Fix;
+
+// SAFETY: `Fix` is `#[repr(transparent)]` over `Bits`, its only non-zero-sized field.
+unsafe impl<Bits, Base, Exp> Zeroable for Fix<Bits, Base, Exp> where Bits: Zeroable {}
+
+// SAFETY: As above, and `Copy` and `'static` follow from the bounds.
+unsafe impl<Bits, Base, Exp> Pod for Fix<Bits, Base, Exp>
+where
+    Bits: Pod,
+    Base: 'static,
+    Exp: 'static,
+{
+}
+
+macro_rules! impl_pod {
+    ($sign:ident, $bits:tt) => {
+        paste! {
+            #[doc = concat!("A padding-free, `Pod` [`", stringify!([<$sign FixValue $bits>]), "`].")]
+            ///
+            /// See the [module docs](self) for its layout.
+            #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Pod, Zeroable)]
+            #[repr(C)]
+            pub struct [<$sign FixValue $bits Pod>] {
+                bits: [u8; size_of::<[<$sign:lower $bits>]>()],
+                pub exp: i8,
+            }
+
+            impl [<$sign FixValue $bits Pod>] {
+                #[must_use]
+                pub fn new(bits: [<$sign:lower $bits>], exp: i8) -> Self {
+                    Self {
+                        bits: bits.to_le_bytes(),
+                        exp,
+                    }
+                }
+
+                #[must_use]
+                pub fn bits(&self) -> [<$sign:lower $bits>] {
+                    [<$sign:lower $bits>]::from_le_bytes(self.bits)
+                }
+
+                pub fn set_bits(&mut self, bits: [<$sign:lower $bits>]) {
+                    self.bits = bits.to_le_bytes();
+                }
+            }
+
+            impl fmt::Debug for [<$sign FixValue $bits Pod>] {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.debug_struct(stringify!([<$sign FixValue $bits Pod>]))
+                        .field("bits", &self.bits())
+                        .field("exp", &self.exp)
+                        .finish()
+                }
+            }
+
+            impl From<[<$sign FixValue $bits>]> for [<$sign FixValue $bits Pod>] {
+                fn from(value: [<$sign FixValue $bits>]) -> Self {
+                    Self::new(value.bits, value.exp)
+                }
+            }
+
+            impl From<[<$sign FixValue $bits Pod>]> for [<$sign FixValue $bits>] {
+                fn from(value: [<$sign FixValue $bits Pod>]) -> Self {
+                    Self::new(value.bits(), value.exp)
+                }
+            }
+
+            const _: () = {
+                assert!(size_of::<Fix<[<$sign:lower $bits>], (), ()>>() == size_of::<[<$sign:lower $bits>]>());
+                assert!(align_of::<Fix<[<$sign:lower $bits>], (), ()>>() == align_of::<[<$sign:lower $bits>]>());
+                assert!(size_of::<[<$sign FixValue $bits Pod>]>() == size_of::<[<$sign:lower $bits>]>() + 1);
+                assert!(align_of::<[<$sign FixValue $bits Pod>]>() == 1);
+            };
+        }
+    };
+}
+
+impl_pod!(U, 8);
+impl_pod!(U, 16);
+impl_pod!(U, 32);
+impl_pod!(U, 64);
+impl_pod!(U, 128);
+impl_pod!(I, 8);
+impl_pod!(I, 16);
+impl_pod!(I, 32);
+impl_pod!(I, 64);
+impl_pod!(I, 128);
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::{cast_slice, Pod, Zeroable};
+
+    use super::{IFixValue128Pod, UFixValue64Pod};
+    use crate::aliases::decimal::{IFix128, UFix64};
+    use crate::fix_value::{IFixValue128, UFixValue64};
+    use crate::typenum::{N18, N9};
+
+    #[test]
+    fn fix_is_pod() {
+        fn assert_pod<T: Pod>() {}
+        assert_pod::<UFix64<N9>>();
+        assert_pod::<IFix128<N18>>();
+        assert_eq!(UFix64::<N9>::zeroed(), UFix64::new(0));
+
+        let prices = [UFix64::<N9>::new(1), UFix64::new(u64::MAX)];
+        let bits: &[u64] = cast_slice(&prices);
+        assert_eq!(bits, [1, u64::MAX]);
+    }
+
+    #[test]
+    fn value_roundtrip() {
+        let value = IFixValue128::new(i128::MIN, -18);
+        let pod = IFixValue128Pod::from(value);
+        assert_eq!(pod.bits(), i128::MIN);
+        assert_eq!(IFixValue128::from(pod), value);
+
+        let mut pod = UFixValue64Pod::zeroed();
+        pod.set_bits(7);
+        pod.exp = 3;
+        assert_eq!(UFixValue64::from(pod), UFixValue64::new(7, 3));
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn bytes_match_borsh() {
+        let value = IFixValue128::new(-123_456_789, -9);
+        let pod = IFixValue128Pod::from(value);
+        assert_eq!(bytemuck::bytes_of(&pod), borsh::to_vec(&value).unwrap());
+    }
+
+    #[test]
+    fn arrays_are_packed() {
+        let prices = [UFixValue64Pod::new(1, -9), UFixValue64Pod::new(2, -6)];
+        let bytes: &[u8] = cast_slice(&prices);
+        assert_eq!(
+            bytes,
+            [1, 0, 0, 0, 0, 0, 0, 0, 0xf7, 2, 0, 0, 0, 0, 0, 0, 0, 0xfa]
+        );
+        let back: &[UFixValue64Pod] = cast_slice(bytes);
+        assert_eq!(back, prices);
+    }
+
+    #[test]
+    fn debug_shows_bits() {
+        assert_eq!(
+            format!("{:?}", IFixValue128Pod::new(-1_500, -3)),
+            "IFixValue128Pod { bits: -1500, exp: -3 }"
+        );
+    }
+}
@@ -1,9 +1,12 @@
 pub use crate::aliases::decimal::*;
+pub use crate::error::*;
 pub use crate::fix_value::*;
 pub use crate::muldiv::MulDiv;
 pub use crate::num_traits::{
     CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, SaturatingAdd, SaturatingSub,
 };
+pub use crate::parse::*;
+pub use crate::rounding::*;
 pub use crate::typenum::{N1, N10, N11, N12, N2, N3, N4, N5, N6, N7, N8, N9};
 pub use crate::util::*;
 pub use crate::*;
@@ -0,0 +1,445 @@
+use core::cmp::Ordering;
+
+use num_traits::PrimInt;
+
+/// Rounding mode for results that are not representable at the target precision.
+///
+/// Directed modes round toward a fixed direction; the `Half*` modes round to the nearest
+/// representable value and differ only in how exact ties are broken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Toward negative infinity.
+    Floor,
+    /// Toward positive infinity.
+    Ceil,
+    /// Toward zero, i.e. truncation.
+    TowardZero,
+    /// Away from zero.
+    AwayFromZero,
+    /// To nearest, ties away from zero.
+    HalfUp,
+    /// To nearest, ties toward zero.
+    HalfDown,
+    /// To nearest, ties to the even neighbour (banker's rounding).
+    HalfEven,
+}
+
+/// The part of a value dropped by truncation, relative to half a unit of the last kept digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Discarded {
+    Zero,
+    BelowHalf,
+    Half,
+    AboveHalf,
+}
+
+impl Discarded {
+    /// Classifies dropped decimal digits, most significant first.
+    pub(crate) fn of_digits(mut digits: impl Iterator<Item = u8>) -> Self {
+        let first = digits.next();
+        let sticky = digits.any(|digit| digit != 0);
+        match first {
+            None | Some(0) if !sticky => Self::Zero,
+            Some(5) if !sticky => Self::Half,
+            Some(0..=4) => Self::BelowHalf,
+            _ => Self::AboveHalf,
+        }
+    }
+
+    /// Classifies the remainder of a division by `divisor`. `sticky` marks a nonzero part below
+    /// the remainder, dropped by an earlier division; it only breaks ties if `divisor` is even.
+    pub(crate) fn of_remainder<U: PrimInt>(remainder: U, divisor: U, sticky: bool) -> Self {
+        match remainder.cmp(&(divisor - remainder)) {
+            _ if remainder.is_zero() && !sticky => Self::Zero,
+            Ordering::Less => Self::BelowHalf,
+            Ordering::Equal if !sticky => Self::Half,
+            Ordering::Equal | Ordering::Greater => Self::AboveHalf,
+        }
+    }
+}
+
+impl Rounding {
+    /// Whether a magnitude truncated toward zero must be bumped one unit away from zero.
+    ///
+    /// `odd` is the parity of the truncated magnitude, used to break ties in `HalfEven`.
+    pub(crate) fn bumps(self, negative: bool, odd: bool, discarded: Discarded) -> bool {
+        match (self, discarded) {
+            (_, Discarded::Zero) | (Self::TowardZero, _) => false,
+            (Self::Floor, _) => negative,
+            (Self::Ceil, _) => !negative,
+            (Self::AwayFromZero, _)
+            | (_, Discarded::AboveHalf)
+            | (Self::HalfUp, Discarded::Half) => true,
+            (_, Discarded::BelowHalf) | (Self::HalfDown, Discarded::Half) => false,
+            (Self::HalfEven, Discarded::Half) => odd,
+        }
+    }
+}
+
+/// Rounds a truncated quotient of the given sign by its remainder. `None` if bumping it overflows.
+fn round_quotient<U: PrimInt>(
+    quotient: U,
+    remainder: U,
+    divisor: U,
+    negative: bool,
+    rounding: Rounding,
+) -> Option<U> {
+    let discarded = Discarded::of_remainder(remainder, divisor, false);
+    let odd = quotient & U::one() == U::one();
+    if rounding.bumps(negative, odd, discarded) {
+        quotient.checked_add(&U::one())
+    } else {
+        Some(quotient)
+    }
+}
+
+/// Divides a magnitude, rounding a quotient of the given sign.
+fn div_magnitude<U: PrimInt>(
+    magnitude: U,
+    divisor: U,
+    negative: bool,
+    rounding: Rounding,
+) -> Option<U> {
+    round_quotient(
+        magnitude / divisor,
+        magnitude % divisor,
+        divisor,
+        negative,
+        rounding,
+    )
+}
+
+/// The full 256-bit product of two `u128`, as its high and low halves.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = (1 << 64) - 1;
+    let (a_high, a_low) = (a >> 64, a & MASK);
+    let (b_high, b_low) = (b >> 64, b & MASK);
+    let low_low = a_low * b_low;
+    let high_low = a_high * b_low;
+    let low_high = a_low * b_high;
+    // Below 3 × 2^64, so it cannot overflow.
+    let middle = (low_low >> 64) + (high_low & MASK) + (low_high & MASK);
+    let low = (middle << 64) | (low_low & MASK);
+    let high = a_high * b_high + (high_low >> 64) + (low_high >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// Divides the 256-bit `high` and `low` halves by `divisor`, returning the quotient and remainder.
+/// `None` if the quotient does not fit in `u128`, or if `divisor` is zero.
+fn div_wide(high: u128, low: u128, divisor: u128) -> Option<(u128, u128)> {
+    if high >= divisor {
+        return None;
+    }
+    let (mut quotient, mut remainder) = (0, high);
+    for bit in (0..128).rev() {
+        // The remainder stays below the divisor, so shifting it loses at most this one bit.
+        let carry = remainder >> 127 == 1;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        if carry || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1 << bit;
+        }
+    }
+    Some((quotient, remainder))
+}
+
+/// Like [`MulDiv`], but with the rounding mode chosen at runtime.
+///
+/// [`MulDiv`]: ../muldiv/trait.MulDiv.html
+pub trait MulDivWith<Rhs = Self> {
+    type Output;
+
+    /// Calculates `self × num ÷ denom` exactly, then rounds with `rounding`.
+    /// Returns `None` if the result does not fit, or if `denom` is zero.
+    fn mul_div_with(self, num: Rhs, denom: Rhs, rounding: Rounding) -> Option<Self::Output>;
+}
+
+macro_rules! impl_mul_div_with_unsigned {
+    ($ty:ident, $wide:ident) => {
+        impl MulDivWith for $ty {
+            type Output = $ty;
+            fn mul_div_with(self, num: $ty, denom: $ty, rounding: Rounding) -> Option<$ty> {
+                if denom == 0 {
+                    return None;
+                }
+                let product = $wide::from(self) * $wide::from(num);
+                let quotient = div_magnitude(product, $wide::from(denom), false, rounding)?;
+                $ty::try_from(quotient).ok()
+            }
+        }
+    };
+}
+
+macro_rules! impl_mul_div_with_signed {
+    ($ty:ident, $wide:ident) => {
+        impl MulDivWith for $ty {
+            type Output = $ty;
+            fn mul_div_with(self, num: $ty, denom: $ty, rounding: Rounding) -> Option<$ty> {
+                if denom == 0 {
+                    return None;
+                }
+                let product = $wide::from(self) * $wide::from(num);
+                let negative = (product < 0) != (denom < 0);
+                let magnitude = div_magnitude(
+                    product.unsigned_abs(),
+                    denom.unsigned_abs().into(),
+                    negative,
+                    rounding,
+                )?;
+                let quotient = $wide::try_from(magnitude).ok()?;
+                $ty::try_from(if negative { -quotient } else { quotient }).ok()
+            }
+        }
+    };
+}
+
+impl_mul_div_with_unsigned!(u8, u16);
+impl_mul_div_with_unsigned!(u16, u32);
+impl_mul_div_with_unsigned!(u32, u64);
+impl_mul_div_with_unsigned!(u64, u128);
+impl_mul_div_with_signed!(i8, i16);
+impl_mul_div_with_signed!(i16, i32);
+impl_mul_div_with_signed!(i32, i64);
+impl_mul_div_with_signed!(i64, i128);
+
+impl MulDivWith for u128 {
+    type Output = u128;
+    fn mul_div_with(self, num: u128, denom: u128, rounding: Rounding) -> Option<u128> {
+        let (high, low) = widening_mul(self, num);
+        let (quotient, remainder) = div_wide(high, low, denom)?;
+        round_quotient(quotient, remainder, denom, false, rounding)
+    }
+}
+
+impl MulDivWith for i128 {
+    type Output = i128;
+    fn mul_div_with(self, num: i128, denom: i128, rounding: Rounding) -> Option<i128> {
+        let negative = (self < 0) ^ (num < 0) ^ (denom < 0);
+        let (high, low) = widening_mul(self.unsigned_abs(), num.unsigned_abs());
+        let divisor = denom.unsigned_abs();
+        let (quotient, remainder) = div_wide(high, low, divisor)?;
+        let magnitude = round_quotient(quotient, remainder, divisor, negative, rounding)?;
+        if negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+}
+
+// Through the 64-bit impls, which hold `usize` and `isize` on every supported target.
+macro_rules! impl_mul_div_with_size {
+    ($ty:ident, $via:ident) => {
+        impl MulDivWith for $ty {
+            type Output = $ty;
+            fn mul_div_with(self, num: $ty, denom: $ty, rounding: Rounding) -> Option<$ty> {
+                let num = $via::try_from(num).ok()?;
+                let denom = $via::try_from(denom).ok()?;
+                let quotient = $via::try_from(self)
+                    .ok()?
+                    .mul_div_with(num, denom, rounding)?;
+                $ty::try_from(quotient).ok()
+            }
+        }
+    };
+}
+
+impl_mul_div_with_size!(usize, u64);
+impl_mul_div_with_size!(isize, i64);
+
+/// `floor(self × num ÷ denom)` wrapped around at the bounds of the type, and whether it overflowed.
+pub(crate) trait OverflowingMulDivFloor: Sized {
+    fn overflowing_mul_div_floor(self, num: Self, denom: Self) -> (Self, bool);
+}
+
+macro_rules! impl_overflowing_mul_div_floor {
+    ($ty:ident, $wide:ident) => {
+        impl OverflowingMulDivFloor for $ty {
+            #[allow(clippy::cast_possible_truncation)]
+            fn overflowing_mul_div_floor(self, num: $ty, denom: $ty) -> ($ty, bool) {
+                let product = $wide::from(self) * $wide::from(num);
+                let floor = product.div_euclid($wide::from(denom));
+                (floor as $ty, $ty::try_from(floor).is_err())
+            }
+        }
+    };
+}
+
+impl_overflowing_mul_div_floor!(u8, u128);
+impl_overflowing_mul_div_floor!(u16, u128);
+impl_overflowing_mul_div_floor!(u32, u128);
+impl_overflowing_mul_div_floor!(u64, u128);
+impl_overflowing_mul_div_floor!(i8, i128);
+impl_overflowing_mul_div_floor!(i16, i128);
+impl_overflowing_mul_div_floor!(i32, i128);
+impl_overflowing_mul_div_floor!(i64, i128);
+
+impl OverflowingMulDivFloor for u128 {
+    fn overflowing_mul_div_floor(self, num: u128, denom: u128) -> (u128, bool) {
+        let (high, low) = widening_mul(self, num);
+        // Never `None`, as the high half is reduced below the divisor.
+        let (quotient, _) = div_wide(high % denom, low, denom).unwrap_or_default();
+        (quotient, high >= denom)
+    }
+}
+
+impl OverflowingMulDivFloor for i128 {
+    #[allow(clippy::cast_possible_wrap)]
+    fn overflowing_mul_div_floor(self, num: i128, denom: i128) -> (i128, bool) {
+        let negative = (self < 0) ^ (num < 0) ^ (denom < 0);
+        let (high, low) = widening_mul(self.unsigned_abs(), num.unsigned_abs());
+        let divisor = denom.unsigned_abs();
+        // Never `None`, as the high half is reduced below the divisor.
+        let (quotient, remainder) = div_wide(high % divisor, low, divisor).unwrap_or_default();
+        // Flooring a negative quotient rounds its magnitude up.
+        let (magnitude, carry) = quotient.overflowing_add(u128::from(negative && remainder != 0));
+        let wide = high >= divisor || carry;
+        if negative {
+            let overflowed = wide || magnitude > i128::MIN.unsigned_abs();
+            (magnitude.wrapping_neg() as i128, overflowed)
+        } else {
+            (
+                magnitude as i128,
+                wide || magnitude > i128::MAX.unsigned_abs(),
+            )
+        }
+    }
+}
+
+// Through the 64-bit impls, which hold `usize` and `isize` on every supported target, so only
+// narrowing the result back can lose bits.
+macro_rules! impl_overflowing_mul_div_floor_size {
+    ($ty:ident, $via:ident) => {
+        impl OverflowingMulDivFloor for $ty {
+            #[allow(clippy::cast_possible_truncation)]
+            fn overflowing_mul_div_floor(self, num: $ty, denom: $ty) -> ($ty, bool) {
+                let (floor, overflowed) =
+                    (self as $via).overflowing_mul_div_floor(num as $via, denom as $via);
+                (floor as $ty, overflowed || $ty::try_from(floor).is_err())
+            }
+        }
+    };
+}
+
+impl_overflowing_mul_div_floor_size!(usize, u64);
+impl_overflowing_mul_div_floor_size!(isize, i64);
+
+#[cfg(test)]
+mod tests {
+    use super::{Discarded, MulDivWith, Rounding};
+    use crate::muldiv::MulDiv;
+
+    #[test]
+    fn exact_never_bumps() {
+        for rounding in [
+            Rounding::Floor,
+            Rounding::Ceil,
+            Rounding::TowardZero,
+            Rounding::AwayFromZero,
+            Rounding::HalfUp,
+            Rounding::HalfDown,
+            Rounding::HalfEven,
+        ] {
+            assert!(!rounding.bumps(false, true, Discarded::Zero));
+            assert!(!rounding.bumps(true, true, Discarded::Zero));
+        }
+    }
+
+    #[test]
+    fn directed_follow_sign() {
+        assert!(Rounding::Floor.bumps(true, false, Discarded::BelowHalf));
+        assert!(!Rounding::Floor.bumps(false, false, Discarded::AboveHalf));
+        assert!(Rounding::Ceil.bumps(false, false, Discarded::BelowHalf));
+        assert!(!Rounding::Ceil.bumps(true, false, Discarded::AboveHalf));
+    }
+
+    #[test]
+    fn mul_div_with_matches_muldiv() {
+        for a in -20i8..=20 {
+            for d in [-7i8, -3, -2, 2, 3, 7] {
+                assert_eq!(a.mul_div_with(5, d, Rounding::Floor), a.mul_div_floor(5, d));
+                assert_eq!(a.mul_div_with(5, d, Rounding::Ceil), a.mul_div_ceil(5, d));
+                assert_eq!(
+                    a.mul_div_with(5, d, Rounding::HalfUp),
+                    a.mul_div_round(5, d)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn mul_div_with_ties() {
+        assert_eq!(5u64.mul_div_with(1, 2, Rounding::HalfEven), Some(2));
+        assert_eq!(7u64.mul_div_with(1, 2, Rounding::HalfEven), Some(4));
+        assert_eq!(5u64.mul_div_with(1, 2, Rounding::HalfDown), Some(2));
+        assert_eq!((-5i64).mul_div_with(1, 2, Rounding::HalfDown), Some(-2));
+        assert_eq!((-5i64).mul_div_with(1, 2, Rounding::HalfUp), Some(-3));
+        assert_eq!(5i64.mul_div_with(1, -2, Rounding::TowardZero), Some(-2));
+        assert_eq!(5i64.mul_div_with(1, -2, Rounding::AwayFromZero), Some(-3));
+    }
+
+    #[test]
+    fn mul_div_with_extremes() {
+        assert_eq!(
+            i64::MIN.mul_div_with(i64::MIN, i64::MIN, Rounding::Floor),
+            Some(i64::MIN)
+        );
+        assert_eq!(i64::MIN.mul_div_with(-1, 1, Rounding::Floor), None);
+        assert_eq!(
+            u64::MAX.mul_div_with(u64::MAX, u64::MAX, Rounding::Ceil),
+            Some(u64::MAX)
+        );
+        assert_eq!(u64::MAX.mul_div_with(2, 1, Rounding::Floor), None);
+        assert_eq!(1u8.mul_div_with(1, 0, Rounding::Floor), None);
+    }
+
+    #[test]
+    fn mul_div_with_128_bit_extremes() {
+        // (2^127 − 1) × 3 ÷ 6 is a tie between 2^126 − 1 and 2^126, via a 256-bit product.
+        let max = i128::MAX;
+        assert_eq!(max.mul_div_with(3, 6, Rounding::HalfEven), Some(1 << 126));
+        assert_eq!(
+            max.mul_div_with(3, 6, Rounding::HalfDown),
+            Some((1 << 126) - 1)
+        );
+        assert_eq!(
+            (-max).mul_div_with(3, 6, Rounding::HalfEven),
+            Some(-(1 << 126))
+        );
+        assert_eq!(
+            (-max).mul_div_with(3, 6, Rounding::HalfDown),
+            Some(-(1 << 126) + 1)
+        );
+        assert_eq!(
+            i128::MIN.mul_div_with(i128::MIN, i128::MIN, Rounding::HalfEven),
+            Some(i128::MIN)
+        );
+        assert_eq!(max.mul_div_with(max, max, Rounding::HalfDown), Some(max));
+        assert_eq!(i128::MIN.mul_div_with(-1, 1, Rounding::HalfEven), None);
+        assert_eq!(
+            u128::MAX.mul_div_with(u128::MAX, u128::MAX, Rounding::HalfEven),
+            Some(u128::MAX)
+        );
+        assert_eq!(u128::MAX.mul_div_with(3, 2, Rounding::HalfDown), None);
+        assert_eq!(
+            u128::MAX.mul_div_with(1, 2, Rounding::HalfEven),
+            Some(1 << 127)
+        );
+        assert_eq!(1u128.mul_div_with(1, 0, Rounding::HalfEven), None);
+    }
+
+    #[test]
+    fn mul_div_with_size() {
+        assert_eq!(5usize.mul_div_with(1, 2, Rounding::HalfEven), Some(2));
+        assert_eq!((-5isize).mul_div_with(1, 2, Rounding::HalfUp), Some(-3));
+        assert_eq!(usize::MAX.mul_div_with(2, 1, Rounding::Floor), None);
+    }
+
+    #[test]
+    fn ties() {
+        assert!(Rounding::HalfUp.bumps(false, false, Discarded::Half));
+        assert!(!Rounding::HalfDown.bumps(false, false, Discarded::Half));
+        assert!(!Rounding::HalfEven.bumps(false, false, Discarded::Half));
+        assert!(Rounding::HalfEven.bumps(true, true, Discarded::Half));
+    }
+}
@@ -0,0 +1,544 @@
+//! Serde serialization of `Fix`.
+//!
+//! By default `Fix` serializes exactly as its _Bits_, with _Base_ and _Exp_ fixed by the type. The
+//! modules here select another representation with `#[serde(with = "...")]`:
+//!
+//! - [`as_string`]: the exact decimal string written by `Display`, e.g. `"1.500000"`. Lossless,
+//!   and safe from the 53-bit integer limit of JavaScript.
+//! - [`as_bits`]: the raw integer _Bits_, the same as the default.
+//! - [`as_struct`]: `{"bits": …, "exp": …}`, like `FixValue`, checking `exp` when deserializing.
+//!
+//! `FixValue` serializes as `{"bits": …, "exp": …}` by default, or as a decimal string with
+//! [`value_as_string`].
+//!
+//! # Examples
+//!
+//! ```
+//! use fix::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Quote {
+//!     #[serde(with = "fix::serialize::as_string")]
+//!     price: UFix64<N6>,
+//!     #[serde(with = "fix::serialize::as_struct")]
+//!     fee: UFix64<N4>,
+//!     size: UFix64<N3>,
+//! }
+//!
+//! let quote = Quote {
+//!     price: UFix64::new(1_500_000),
+//!     fee: UFix64::new(25),
+//!     size: UFix64::new(2_000),
+//! };
+//! let json = r#"{"price":"1.500000","fee":{"bits":25,"exp":-4},"size":2000}"#;
+//! assert_eq!(serde_json::to_string(&quote).unwrap(), json);
+//! assert_eq!(serde_json::from_str::<Quote>(json).unwrap(), quote);
+//! ```
+
+// The `serialize` and `deserialize` functions fail exactly when the serializer or the input does.
+#![allow(clippy::missing_errors_doc)]
+
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+
+use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
+use serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::display::fmt_decimal;
+use crate::fix_value::ExponentMismatch;
+use crate::num_traits::{CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, Zero};
+use crate::parse::{saturate_exp, Decimal, ParseFixError};
+use crate::rounding::Rounding;
+use crate::typenum::{Integer, U10};
+use crate::Fix;
+
+impl<Bits, Base, Exp> Serialize for Fix<Bits, Base, Exp>
+where
+    Bits: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits.serialize(serializer)
+    }
+}
+
+impl<'de, Bits, Base, Exp> Deserialize<'de> for Fix<Bits, Base, Exp>
+where
+    Bits: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Bits::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// Exact decimal string, as written by `Display` and read by `FromStr`.
+///
+/// Deserializing accepts any form `FromStr` does, but fails on more decimals than _Exp_ holds.
+/// The [`rounded`](as_string::rounded) modules round them instead.
+pub mod as_string {
+    use super::{
+        de, fmt, CheckedAdd, CheckedMul, CheckedSub, Deserializer, Display, Fix, Formatter,
+        FromPrimitive, Integer, PhantomData, Rounding, Serializer, Visitor, Zero, U10,
+    };
+
+    pub fn serialize<Bits, Base, Exp, S>(
+        fix: &Fix<Bits, Base, Exp>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        Fix<Bits, Base, Exp>: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(fix)
+    }
+
+    pub fn deserialize<'de, Bits, Exp, D>(deserializer: D) -> Result<Fix<Bits, U10, Exp>, D::Error>
+    where
+        Bits: Copy + Zero + FromPrimitive + CheckedAdd + CheckedSub + CheckedMul,
+        Exp: Integer,
+        D: Deserializer<'de>,
+    {
+        deserialize_with(deserializer, None)
+    }
+
+    fn deserialize_with<'de, Bits, Exp, D>(
+        deserializer: D,
+        rounding: Option<Rounding>,
+    ) -> Result<Fix<Bits, U10, Exp>, D::Error>
+    where
+        Bits: Copy + Zero + FromPrimitive + CheckedAdd + CheckedSub + CheckedMul,
+        Exp: Integer,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StrVisitor {
+            rounding,
+            marker: PhantomData,
+        })
+    }
+
+    struct StrVisitor<Bits, Exp> {
+        rounding: Option<Rounding>,
+        marker: PhantomData<(Bits, Exp)>,
+    }
+
+    impl<Bits, Exp> Visitor<'_> for StrVisitor<Bits, Exp>
+    where
+        Bits: Copy + Zero + FromPrimitive + CheckedAdd + CheckedSub + CheckedMul,
+        Exp: Integer,
+    {
+        type Value = Fix<Bits, U10, Exp>;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a decimal string")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+            match self.rounding {
+                None => s.parse(),
+                Some(rounding) => Fix::from_str_rounded(s, rounding),
+            }
+            .map_err(E::custom)
+        }
+    }
+
+    /// Like [`as_string`](super::as_string), but rounds decimals beyond the precision of _Exp_
+    /// when deserializing, with one module per [`Rounding`] mode.
+    pub mod rounded {
+        macro_rules! rounded {
+            ($name:ident, $rounding:ident) => {
+                #[doc = concat!(
+                                    "Rounds with [`Rounding::", stringify!($rounding), "`]",
+                                    "(crate::rounding::Rounding::", stringify!($rounding), ")."
+                                )]
+                pub mod $name {
+                    use super::super::{
+                        deserialize_with, CheckedAdd, CheckedMul, CheckedSub, Deserializer, Fix,
+                        FromPrimitive, Integer, Rounding, Zero, U10,
+                    };
+
+                    pub use super::super::serialize;
+
+                    pub fn deserialize<'de, Bits, Exp, D>(
+                        deserializer: D,
+                    ) -> Result<Fix<Bits, U10, Exp>, D::Error>
+                    where
+                        Bits: Copy + Zero + FromPrimitive + CheckedAdd + CheckedSub + CheckedMul,
+                        Exp: Integer,
+                        D: Deserializer<'de>,
+                    {
+                        deserialize_with(deserializer, Some(Rounding::$rounding))
+                    }
+                }
+            };
+        }
+
+        rounded!(floor, Floor);
+        rounded!(ceil, Ceil);
+        rounded!(toward_zero, TowardZero);
+        rounded!(away_from_zero, AwayFromZero);
+        rounded!(half_up, HalfUp);
+        rounded!(half_down, HalfDown);
+        rounded!(half_even, HalfEven);
+    }
+}
+
+/// Raw integer _Bits_, the same as the default representation.
+pub mod as_bits {
+    use super::{Deserialize, Deserializer, Fix, Serialize, Serializer};
+
+    pub fn serialize<Bits, Base, Exp, S>(
+        fix: &Fix<Bits, Base, Exp>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        Bits: Serialize,
+        S: Serializer,
+    {
+        fix.bits.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, Bits, Base, Exp, D>(
+        deserializer: D,
+    ) -> Result<Fix<Bits, Base, Exp>, D::Error>
+    where
+        Bits: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Bits::deserialize(deserializer).map(Fix::new)
+    }
+}
+
+/// `{"bits": …, "exp": …}`, the representation of `FixValue`. Deserializing fails with
+/// [`ExponentMismatch`] unless `exp` is the type's _Exp_.
+pub mod as_struct {
+    use super::{
+        de, Deserialize, Deserializer, ExponentMismatch, Fix, Integer, Serialize, Serializer,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Fix", deny_unknown_fields)]
+    struct Repr<Bits> {
+        bits: Bits,
+        exp: i8,
+    }
+
+    pub fn serialize<Bits, Base, Exp, S>(
+        fix: &Fix<Bits, Base, Exp>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        Bits: Serialize,
+        Exp: Integer,
+        S: Serializer,
+    {
+        Repr {
+            bits: &fix.bits,
+            exp: Exp::to_i8(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, Bits, Base, Exp, D>(
+        deserializer: D,
+    ) -> Result<Fix<Bits, Base, Exp>, D::Error>
+    where
+        Bits: Deserialize<'de>,
+        Exp: Integer,
+        D: Deserializer<'de>,
+    {
+        let Repr { bits, exp } = Repr::deserialize(deserializer)?;
+        if exp == Exp::to_i8() {
+            Ok(Fix::new(bits))
+        } else {
+            Err(de::Error::custom(ExponentMismatch {
+                expected: Exp::to_i8(),
+                actual: exp,
+            }))
+        }
+    }
+}
+
+/// Decimal string for `FixValue`, e.g. `"1.500000"` for `bits` 1 500 000 and `exp` −6.
+///
+/// Every fractional digit is written, so the string parses back to the same `exp`. A positive
+/// `exp` is written as a suffix, e.g. `"15e2"`. Deserializing infers `exp` from the fractional
+/// digits and the suffix, and also accepts the default `{"bits": …, "exp": …}` form.
+///
+/// # Examples
+///
+/// ```
+/// use fix::prelude::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Oracle {
+///     #[serde(with = "fix::serialize::value_as_string")]
+///     price: UFixValue128,
+/// }
+///
+/// let oracle = Oracle { price: UFixValue128::new(1_500_000, -6) };
+/// let json = serde_json::to_string(&oracle).unwrap();
+/// assert_eq!(json, r#"{"price":"1.500000"}"#);
+/// assert_eq!(serde_json::from_str::<Oracle>(&json).unwrap(), oracle);
+/// let legacy = r#"{"price":{"bits":1500000,"exp":-6}}"#;
+/// assert_eq!(serde_json::from_str::<Oracle>(legacy).unwrap(), oracle);
+/// ```
+pub mod value_as_string {
+    use super::{
+        de, fmt, Deserialize, Deserializer, Display, Formatter, MapAccess, MapAccessDeserializer,
+        PhantomData, SeqAccess, SeqAccessDeserializer, Serializer, Visitor,
+    };
+    use crate::parse::ParseFixError;
+
+    /// A `FixValue` type.
+    #[doc(hidden)]
+    pub trait DecimalValue: Sized {
+        fn fmt_decimal(&self, f: &mut Formatter) -> fmt::Result;
+        fn parse_decimal(s: &str) -> Result<Self, ParseFixError>;
+    }
+
+    pub fn serialize<V, S>(value: &V, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        V: DecimalValue,
+        S: Serializer,
+    {
+        serializer.collect_str(&AsDisplay(value))
+    }
+
+    pub fn deserialize<'de, V, D>(deserializer: D) -> Result<V, D::Error>
+    where
+        V: DecimalValue + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ValueVisitor(PhantomData))
+    }
+
+    struct AsDisplay<'a, V>(&'a V);
+
+    impl<V: DecimalValue> Display for AsDisplay<'_, V> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            self.0.fmt_decimal(f)
+        }
+    }
+
+    struct ValueVisitor<V>(PhantomData<V>);
+
+    impl<'de, V> Visitor<'de> for ValueVisitor<V>
+    where
+        V: DecimalValue + Deserialize<'de>,
+    {
+        type Value = V;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a decimal string or a `{bits, exp}` struct")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<V, E> {
+            V::parse_decimal(s).map_err(E::custom)
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<V, A::Error> {
+            V::deserialize(MapAccessDeserializer::new(map))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<V, A::Error> {
+            V::deserialize(SeqAccessDeserializer::new(seq))
+        }
+    }
+}
+
+/// Writes _bits × 10<sup>exp</sup>_ for [`value_as_string`].
+pub(crate) fn write_decimal_value<Bits: Display>(
+    f: &mut Formatter,
+    bits: &Bits,
+    exp: i8,
+) -> fmt::Result {
+    if exp > 0 {
+        write!(f, "{bits}e{exp}")
+    } else {
+        fmt_decimal(f, bits, exp.into(), Rounding::HalfEven)
+    }
+}
+
+/// Parses bits and the exponent of the last written digit for [`value_as_string`].
+pub(crate) fn parse_decimal_value<Bits>(s: &str) -> Result<(Bits, i8), ParseFixError>
+where
+    Bits: Copy + Zero + FromPrimitive + CheckedAdd + CheckedSub + CheckedMul,
+{
+    let decimal = Decimal::parse(s)?;
+    let exp = saturate_exp(decimal.exp());
+    decimal.to_bits(exp.into(), None).map(|bits| (bits, exp))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::aliases::binary;
+    use crate::aliases::decimal::{IFix64, UFix128, UFix64};
+    use crate::fix_value::{IFixValue64, UFixValue128, UFixValue8};
+    use crate::typenum::{N18, N2, N3, N6, N8};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Strings {
+        #[serde(with = "super::as_string")]
+        price: IFix64<N6>,
+        #[serde(with = "super::as_string")]
+        big: UFix128<N18>,
+        #[serde(serialize_with = "super::as_string::serialize")]
+        binary: binary::UFix64<N8>,
+    }
+
+    #[test]
+    fn default_is_bits() {
+        let x = IFix64::<N3>::new(-1_500);
+        assert_eq!(serde_json::to_string(&x).unwrap(), "-1500");
+        assert_eq!(serde_json::from_str::<IFix64<N3>>("-1500").unwrap(), x);
+    }
+
+    #[test]
+    fn as_string_serializes_exact_decimal() {
+        let value = Strings {
+            price: IFix64::new(-1_500_000),
+            big: UFix128::new(u128::MAX),
+            binary: binary::UFix64::new(0x180),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            json,
+            r#"{"price":"-1.500000","big":"340282366920938463463.374607431768211455","binary":"1.50000000"}"#
+        );
+    }
+
+    #[test]
+    fn as_string_rejects_excess_precision() {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Price(#[serde(deserialize_with = "super::as_string::deserialize")] UFix64<N2>);
+
+        assert_eq!(
+            serde_json::from_str::<Price>(r#""1.5""#).unwrap(),
+            Price(UFix64::new(150))
+        );
+        let error = serde_json::from_str::<Price>(r#""1.505""#).unwrap_err();
+        assert!(error.to_string().contains("too many decimals"));
+        assert!(serde_json::from_str::<Price>("150").is_err());
+    }
+
+    #[test]
+    fn as_string_rounded() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Prices {
+            #[serde(with = "super::as_string::rounded::half_even")]
+            half_even: IFix64<N2>,
+            #[serde(with = "super::as_string::rounded::floor")]
+            floor: IFix64<N2>,
+        }
+
+        let prices: Prices =
+            serde_json::from_str(r#"{"half_even":"-1.005","floor":"-1.001"}"#).unwrap();
+        assert_eq!(
+            prices,
+            Prices {
+                half_even: IFix64::new(-100),
+                floor: IFix64::new(-101),
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&prices).unwrap(),
+            r#"{"half_even":"-1.00","floor":"-1.01"}"#
+        );
+    }
+
+    #[test]
+    fn as_bits() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Bits(#[serde(with = "super::as_bits")] UFix64<N6>);
+
+        let bits = Bits(UFix64::new(1_500_000));
+        assert_eq!(serde_json::to_string(&bits).unwrap(), "1500000");
+        assert_eq!(serde_json::from_str::<Bits>("1500000").unwrap(), bits);
+    }
+
+    #[test]
+    fn as_struct_checks_exp() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Struct(#[serde(with = "super::as_struct")] IFix64<N3>);
+
+        let value = Struct(IFix64::new(-25));
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"bits":-25,"exp":-3}"#);
+        assert_eq!(serde_json::from_str::<Struct>(&json).unwrap(), value);
+
+        let error = serde_json::from_str::<Struct>(r#"{"bits":-25,"exp":-2}"#).unwrap_err();
+        assert!(error.to_string().contains("expected: -3, got: -2"));
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Values {
+        #[serde(with = "super::value_as_string")]
+        small: UFixValue8,
+        #[serde(with = "super::value_as_string")]
+        signed: IFixValue64,
+        #[serde(with = "super::value_as_string")]
+        big: UFixValue128,
+    }
+
+    #[test]
+    fn value_as_string_roundtrip() {
+        let values = Values {
+            small: UFixValue8::new(255, 0),
+            signed: IFixValue64::new(-5, -3),
+            big: UFixValue128::new(u128::MAX, -18),
+        };
+        let json = serde_json::to_string(&values).unwrap();
+        assert_eq!(
+            json,
+            r#"{"small":"255","signed":"-0.005","big":"340282366920938463463.374607431768211455"}"#
+        );
+        assert_eq!(serde_json::from_str::<Values>(&json).unwrap(), values);
+    }
+
+    #[test]
+    fn value_as_string_positive_exp() {
+        let values = Values {
+            small: UFixValue8::new(0, 3),
+            signed: IFixValue64::new(-15, 2),
+            big: UFixValue128::new(1, 127),
+        };
+        let json = serde_json::to_string(&values).unwrap();
+        assert_eq!(json, r#"{"small":"0e3","signed":"-15e2","big":"1e127"}"#);
+        assert_eq!(serde_json::from_str::<Values>(&json).unwrap(), values);
+    }
+
+    #[test]
+    fn value_as_string_infers_exp() {
+        let values: Values =
+            serde_json::from_str(r#"{"small":"2.50","signed":"-1.5e-3","big":"1e130"}"#).unwrap();
+        assert_eq!(values.small, UFixValue8::new(250, -2));
+        assert_eq!(values.signed, IFixValue64::new(-15, -4));
+        assert_eq!(values.big, UFixValue128::new(1_000, 127));
+    }
+
+    #[test]
+    fn value_as_string_accepts_legacy_struct() {
+        let json = r#"{"small":{"bits":7,"exp":-1},"signed":[-5,-3],"big":"0.0"}"#;
+        let values: Values = serde_json::from_str(json).unwrap();
+        assert_eq!(values.small, UFixValue8::new(7, -1));
+        assert_eq!(values.signed, IFixValue64::new(-5, -3));
+        assert_eq!(values.big, UFixValue128::new(0, -1));
+    }
+
+    #[test]
+    fn value_as_string_rejects_invalid() {
+        let parse = |small: &str| {
+            let json = format!(r#"{{"small":{small},"signed":"0","big":"0"}}"#);
+            serde_json::from_str::<Values>(&json)
+        };
+        assert!(parse(r#""2.56""#).is_err());
+        assert!(parse(r#""-1""#).is_err());
+        assert!(parse(r#""1e-200""#).is_err());
+        assert!(parse(r#""abc""#).is_err());
+        assert!(parse("25").is_err());
+    }
+}
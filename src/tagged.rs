@@ -0,0 +1,270 @@
+//! Self-describing Borsh encoding of `Fix`.
+
+use core::marker::PhantomData;
+use core::ops::{Div, Rem};
+
+#[cfg(feature = "anchor")]
+use anchor_lang::Space;
+use borsh::maybestd::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::error::FixError;
+use crate::fix_value::ExponentMismatch;
+use crate::num_traits::{CheckedMul, Zero};
+use crate::typenum::{Integer, Unsigned};
+use crate::{CheckedPow, Fix, FromUnsigned};
+
+/// Self-describing Borsh encoding of a `Fix`: its _Bits_, then _Exp_ as `i8` and _Base_ as `u8`.
+///
+/// Deserializing fails if the encoded _Base_ differs from the type's. An encoded _Exp_ that
+/// differs is handled by the policy `P`: [`Strict`] fails with [`ExponentMismatch`], while
+/// [`Rescale`] converts the value if it is exactly representable. The underlying `io::Error`
+/// carries the [`ExponentMismatch`] or [`FixError`] when `std` is enabled.
+///
+/// # Examples
+///
+/// ```
+/// use borsh::BorshDeserialize;
+/// use fix::tagged::{Rescale, Tagged};
+/// use fix::prelude::*;
+/// let bytes = borsh::to_vec(&Tagged::new(UFix64::<N6>::new(1_500_000))).unwrap();
+/// assert!(Tagged::<UFix64<N9>>::try_from_slice(&bytes).is_err());
+/// let rescaled = Tagged::<UFix64<N9>, Rescale>::try_from_slice(&bytes).unwrap();
+/// assert_eq!(rescaled.into_inner(), UFix64::<N9>::new(1_500_000_000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Tagged<F, P = Strict> {
+    pub fix: F,
+    policy: PhantomData<P>,
+}
+
+impl<F> Tagged<F> {
+    /// Wraps a `Fix` with the [`Strict`] policy.
+    pub fn new(fix: F) -> Self {
+        Self::with_policy(fix)
+    }
+}
+
+impl<F, P> Tagged<F, P> {
+    /// Wraps a `Fix` with the policy `P`.
+    pub fn with_policy(fix: F) -> Self {
+        Self {
+            fix,
+            policy: PhantomData,
+        }
+    }
+
+    /// Unwraps the `Fix`.
+    pub fn into_inner(self) -> F {
+        self.fix
+    }
+}
+
+impl<Bits, Base, Exp, P> From<Fix<Bits, Base, Exp>> for Tagged<Fix<Bits, Base, Exp>, P> {
+    fn from(fix: Fix<Bits, Base, Exp>) -> Self {
+        Self::with_policy(fix)
+    }
+}
+
+/// How [`Tagged`] handles an encoded _Exp_ other than the type's.
+pub trait ExpPolicy {
+    /// Whether to convert the value to the type's _Exp_ rather than fail.
+    const RESCALE: bool;
+}
+
+/// Fail on a different _Exp_.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Strict;
+
+impl ExpPolicy for Strict {
+    const RESCALE: bool = false;
+}
+
+/// Convert from a different _Exp_, failing unless the value is exactly representable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rescale;
+
+impl ExpPolicy for Rescale {
+    const RESCALE: bool = true;
+}
+
+impl<Bits, Base, Exp, P> BorshSerialize for Tagged<Fix<Bits, Base, Exp>, P>
+where
+    Bits: BorshSerialize,
+    Base: Unsigned,
+    Exp: Integer,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.fix.bits.serialize(writer)?;
+        Exp::to_i8().serialize(writer)?;
+        Base::to_u8().serialize(writer)
+    }
+}
+
+impl<Bits, Base, Exp, P> BorshDeserialize for Tagged<Fix<Bits, Base, Exp>, P>
+where
+    Bits: BorshDeserialize
+        + FromUnsigned
+        + CheckedPow
+        + CheckedMul
+        + Div<Output = Bits>
+        + Rem<Output = Bits>
+        + Copy
+        + Zero
+        + PartialOrd,
+    Base: Unsigned,
+    Exp: Integer,
+    P: ExpPolicy,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let bits = Bits::deserialize_reader(reader)?;
+        let exp = i8::deserialize_reader(reader)?;
+        let base = u8::deserialize_reader(reader)?;
+        if base != Base::to_u8() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Base mismatch deserializing `Fix`.",
+            ));
+        }
+        if exp == Exp::to_i8() {
+            return Ok(Self::with_policy(Fix::new(bits)));
+        }
+        if !P::RESCALE {
+            let mismatch = ExponentMismatch {
+                expected: Exp::to_i8(),
+                actual: exp,
+            };
+            return Err(invalid_data(mismatch));
+        }
+        rescale::<Bits, Base>(bits, exp, Exp::to_i8())
+            .map(|bits| Self::with_policy(Fix::new(bits)))
+            .map_err(invalid_data)
+    }
+}
+
+/// Converts `bits` from _Base<sup>from</sup>_ to _Base<sup>to</sup>_ exactly.
+fn rescale<Bits, Base>(bits: Bits, from: i8, to: i8) -> core::result::Result<Bits, FixError>
+where
+    Bits: FromUnsigned
+        + CheckedPow
+        + CheckedMul
+        + Div<Output = Bits>
+        + Rem<Output = Bits>
+        + Copy
+        + Zero
+        + PartialOrd,
+    Base: Unsigned,
+{
+    let diff = i16::from(from) - i16::from(to);
+    let ratio = Bits::from_unsigned::<Base>().checked_pow(u32::from(diff.unsigned_abs()));
+    let overflow = FixError::out_of_range(bits < Bits::zero());
+    match ratio {
+        _ if bits.is_zero() => Ok(bits),
+        Some(ratio) if diff > 0 => bits.checked_mul(&ratio).ok_or(overflow),
+        None if diff > 0 => Err(overflow),
+        Some(ratio) if (bits % ratio).is_zero() => Ok(bits / ratio),
+        _ => Err(FixError::PrecisionLoss),
+    }
+}
+
+#[cfg(feature = "std")]
+fn invalid_data<E>(error: E) -> Error
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    Error::new(ErrorKind::InvalidData, error)
+}
+
+#[cfg(not(feature = "std"))]
+fn invalid_data<E>(_: E) -> Error {
+    Error::new(ErrorKind::InvalidData, "Invalid tagged `Fix`.")
+}
+
+#[cfg(feature = "anchor")]
+impl<F, P> Space for Tagged<F, P>
+where
+    F: Space,
+{
+    const INIT_SPACE: usize = F::INIT_SPACE + 2;
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::{to_vec, BorshDeserialize};
+
+    use super::{Rescale, Tagged};
+    use crate::aliases::binary;
+    use crate::aliases::decimal::{IFix128, IFix8, UFix64};
+    use crate::typenum::{N18, N3, N9};
+    #[cfg(feature = "std")]
+    use crate::{
+        aliases::decimal::IFix64, error::FixError, fix_value::ExponentMismatch, typenum::N6,
+    };
+
+    #[test]
+    fn tagged_layout() {
+        let bytes = to_vec(&Tagged::new(IFix8::<N3>::new(-1))).unwrap();
+        assert_eq!(bytes, [0xff, 0xfd, 10]);
+        let bytes = to_vec(&Tagged::new(binary::UFix64::<N9>::new(1))).unwrap();
+        assert_eq!(bytes, [1, 0, 0, 0, 0, 0, 0, 0, 0xf7, 2]);
+    }
+
+    #[test]
+    fn tagged_roundtrip() {
+        let value = Tagged::new(IFix128::<N18>::new(-42));
+        let bytes = to_vec(&value).unwrap();
+        assert_eq!(Tagged::try_from_slice(&bytes).unwrap(), value);
+    }
+
+    // `borsh` only keeps error payloads with `std`.
+    #[cfg(feature = "std")]
+    #[test]
+    fn tagged_strict_rejects_other_exp() {
+        let bytes = to_vec(&Tagged::new(UFix64::<N6>::new(1))).unwrap();
+        let error = Tagged::<UFix64<N9>>::try_from_slice(&bytes).unwrap_err();
+        assert_eq!(
+            error.get_ref().unwrap().downcast_ref::<ExponentMismatch>(),
+            Some(&ExponentMismatch {
+                expected: -9,
+                actual: -6
+            })
+        );
+    }
+
+    #[test]
+    fn tagged_rejects_other_base() {
+        let bytes = to_vec(&Tagged::new(binary::UFix64::<N9>::new(1))).unwrap();
+        assert!(Tagged::<UFix64<N9>, Rescale>::try_from_slice(&bytes).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn tagged_rescale() {
+        let rescale = |value: IFix64<N6>| {
+            let bytes = to_vec(&Tagged::new(value)).unwrap();
+            Tagged::<IFix64<N3>, Rescale>::try_from_slice(&bytes)
+                .map(Tagged::into_inner)
+                .map_err(|error| *error.into_inner().unwrap().downcast::<FixError>().unwrap())
+        };
+        assert_eq!(rescale(IFix64::new(-7_000)), Ok(IFix64::new(-7)));
+        assert_eq!(rescale(IFix64::new(-7_001)), Err(FixError::PrecisionLoss));
+
+        let bytes = to_vec(&Tagged::new(IFix64::<N3>::new(i64::MIN))).unwrap();
+        let error = Tagged::<IFix64<N6>, Rescale>::try_from_slice(&bytes).unwrap_err();
+        assert_eq!(
+            error.get_ref().unwrap().downcast_ref::<FixError>(),
+            Some(&FixError::Underflow)
+        );
+    }
+
+    #[test]
+    fn tagged_rescale_extreme_exp() {
+        let bytes = [0, 0, 0, 0, 0, 0, 0, 0, 0x80, 10];
+        let zero = Tagged::<UFix64<N9>, Rescale>::try_from_slice(&bytes).unwrap();
+        assert_eq!(zero.into_inner(), UFix64::new(0));
+        let bytes = [1, 0, 0, 0, 0, 0, 0, 0, 0x80, 10];
+        assert!(Tagged::<UFix64<N9>, Rescale>::try_from_slice(&bytes).is_err());
+        let bytes = [1, 0, 0, 0, 0, 0, 0, 0, 0x7f, 10];
+        assert!(Tagged::<UFix64<N9>, Rescale>::try_from_slice(&bytes).is_err());
+    }
+}
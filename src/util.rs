@@ -2,26 +2,111 @@ use paste::paste;
 #[cfg(feature = "typed-floats")]
 use typed_floats::StrictlyPositiveFinite;
 
+use crate::error::FixError;
 use crate::muldiv::MulDiv;
-use crate::num_traits::ConstZero;
-use crate::typenum::{Integer, NInt, NonZero, Unsigned, U10};
-use crate::Fix;
+use crate::num_traits::{Bounded, ConstZero, One, ToPrimitive, Zero};
+use crate::rounding::{MulDivWith, OverflowingMulDivFloor, Rounding};
+use crate::typenum::{Integer, NInt, NonZero, PInt, Unsigned, U10, Z0};
+use crate::{CheckedPow, Fix, FromUnsigned};
 
 /// Domain specific extensions to the `Fix` type as it's used in this project.
+///
+/// Implemented for any _Base_ with a zero or negative _Exp_. One is not representable with a
+/// positive _Exp_, and using it there, or where _Base<sup>−Exp</sup>_ overflows _Bits_, is a
+/// compile error.
+///
+/// ```compile_fail
+/// use fix::prelude::*;
+/// let _ = fix::aliases::si::Kilo::<u64>::one();
+/// ```
+///
+/// ```compile_fail
+/// use fix::prelude::*;
+/// let _ = UFix8::<N3>::one();
+/// ```
 pub trait FixExt: Sized {
     /// This precision's equivalent of 1.
     const ONE: Self;
 }
 
+/// The step between one and the smallest value at an _Exp_, for rounding arithmetic at any _Exp_.
+///
+/// Implemented for any _Base_ and _Exp_. Where _Base<sup>|Exp|</sup>_ overflows _Bits_ is a
+/// compile error.
+///
+/// ```compile_fail
+/// use fix::prelude::*;
+/// let _ = UFix8::<N3>::new(1).mul_floor(UFix8::new(1));
+/// ```
+pub trait FixScale: Sized {
+    /// _Base<sup>|Exp|</sup>_, which is [`FixExt::ONE`] unless _Exp_ is positive.
+    const SCALE: Self;
+    /// Whether _Exp_ is positive, so that arithmetic multiplies by `SCALE` rather than dividing.
+    const COARSE: bool;
+}
+
 macro_rules! impl_fix_ext {
     ($bits:ident) => {
         paste! {
-            impl<U> FixExt for Fix<$bits, U10, NInt<U>>
+            impl<Base> FixExt for Fix<$bits, Base, Z0>
+            where
+                Base: Unsigned,
+            {
+                const ONE: Self = Fix::constant(1);
+            }
+
+            impl<Base, U> FixExt for Fix<$bits, Base, NInt<U>>
+            where
+                Base: Unsigned,
+                U: Unsigned + NonZero,
+            {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+                const ONE: Self = {
+                    assert!(
+                        Base::U128 <= $bits::MAX as u128,
+                        "`Base` overflows `Bits`"
+                    );
+                    match (Base::U128 as $bits).checked_pow(U::U32) {
+                        Some(one) => Fix::constant(one),
+                        None => panic!("one overflows `Bits` at this `Exp`"),
+                    }
+                };
+            }
+
+            impl<Base> FixScale for Fix<$bits, Base, Z0>
+            where
+                Base: Unsigned,
+            {
+                const SCALE: Self = Fix::constant(1);
+                const COARSE: bool = false;
+            }
+
+            impl<Base, U> FixScale for Fix<$bits, Base, NInt<U>>
             where
+                Base: Unsigned,
                 U: Unsigned + NonZero,
             {
-                const ONE: Self =
-                    Fix::constant((10 as $bits).pow(U::U32));
+                const SCALE: Self = <Self as FixExt>::ONE;
+                const COARSE: bool = false;
+            }
+
+            impl<Base, U> FixScale for Fix<$bits, Base, PInt<U>>
+            where
+                Base: Unsigned,
+                U: Unsigned + NonZero,
+            {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+                const SCALE: Self = {
+                    assert!(
+                        Base::U128 <= $bits::MAX as u128,
+                        "`Base` overflows `Bits`"
+                    );
+                    match (Base::U128 as $bits).checked_pow(U::U32) {
+                        Some(scale) => Fix::constant(scale),
+                        None => panic!("scale overflows `Bits` at this `Exp`"),
+                    }
+                };
+                const COARSE: bool = true;
             }
         }
     };
@@ -54,7 +139,7 @@ where
 macro_rules! impl_to_f64 {
     ($bits:ident) => {
         impl<Exp: Integer> Fix<$bits, U10, Exp> {
-            /// Approximate `f64` value of this fixed-point number.
+            /// Approximate `f64` value of this fixed-point number, as [`ToPrimitive::to_f64`].
             ///
             /// Precision loss above 2^53 bits; intended for offchain
             /// analytics, never for onchain math.
@@ -65,9 +150,9 @@ macro_rules! impl_to_f64 {
             /// assert!((x.to_f64() - 1.5).abs() < f64::EPSILON);
             /// ```
             #[must_use]
-            #[allow(clippy::cast_precision_loss)]
             pub fn to_f64(self) -> f64 {
-                self.bits as f64 * 10f64.powi(Exp::to_i32())
+                // Never `None`, as every primitive integer converts.
+                ToPrimitive::to_f64(&self).unwrap_or(f64::NAN)
             }
         }
     };
@@ -85,10 +170,11 @@ impl<Exp: Integer> Fix<u64, U10, Exp> {
     }
 }
 
-impl<Bits, Exp> Fix<Bits, U10, Exp>
+impl<Bits, Base, Exp> Fix<Bits, Base, Exp>
 where
-    Self: FixExt,
-    Bits: MulDiv<Output = Bits>,
+    Bits: MulDiv<Output = Bits> + FromUnsigned + CheckedPow + One + Zero + Copy,
+    Base: Unsigned,
+    Exp: Integer,
 {
     /// Converts to another _Exp_, returning `None` on overflow.
     ///
@@ -98,13 +184,8 @@ where
     /// let target = source.checked_convert::<N6>();
     /// assert_eq!(target, Some(UFix64::<N6>::new(5_000u64)));
     /// ```
-    pub fn checked_convert<ToExp>(self) -> Option<Fix<Bits, U10, ToExp>>
-    where
-        Fix<Bits, U10, ToExp>: FixExt,
-    {
-        let target_one = Fix::<Bits, U10, ToExp>::one();
-        let source_one = Self::one();
-        target_one.mul_div_floor(self, source_one)
+    pub fn checked_convert<ToExp: Integer>(self) -> Option<Fix<Bits, Base, ToExp>> {
+        self.convert_scaled(MulDiv::mul_div_floor)
     }
 
     /// Converts to another _Exp_ rounding up, returning `None` on overflow.
@@ -115,15 +196,38 @@ where
     /// let target = source.checked_convert_ceil::<N3>();
     /// assert_eq!(target, Some(UFix64::<N3>::new(6u64)));
     /// ```
-    pub fn checked_convert_ceil<ToExp>(self) -> Option<Fix<Bits, U10, ToExp>>
-    where
-        Fix<Bits, U10, ToExp>: FixExt,
-    {
-        let target_one = Fix::<Bits, U10, ToExp>::one();
-        let source_one = Self::one();
-        target_one.mul_div_ceil(self, source_one)
+    pub fn checked_convert_ceil<ToExp: Integer>(self) -> Option<Fix<Bits, Base, ToExp>> {
+        self.convert_scaled(MulDiv::mul_div_ceil)
+    }
+
+    /// Scales by the ratio between the two _Exp_ with `mul_div`, like `try_convert`.
+    fn convert_scaled<ToExp: Integer>(
+        self,
+        mul_div: fn(Bits, Bits, Bits) -> Option<Bits>,
+    ) -> Option<Fix<Bits, Base, ToExp>> {
+        let base = Bits::from_unsigned::<Base>();
+        let diff = Exp::to_i32() - ToExp::to_i32();
+        let one = Bits::one();
+        let bits = match base.checked_pow(diff.unsigned_abs()) {
+            _ if self.bits.is_zero() => self.bits,
+            Some(ratio) if diff >= 0 => mul_div(self.bits, ratio, one)?,
+            Some(ratio) => mul_div(self.bits, one, ratio)?,
+            None if diff >= 0 => return None,
+            // Nested floors and ceilings compose, so a ratio too large for _Bits_ can be divided
+            // out one _Base_ at a time.
+            None => {
+                (0..diff.unsigned_abs()).try_fold(self.bits, |bits, _| mul_div(bits, one, base))?
+            }
+        };
+        Some(Fix::new(bits))
     }
+}
 
+impl<Bits, Base, Exp> Fix<Bits, Base, Exp>
+where
+    Self: FixScale,
+    Bits: MulDiv<Output = Bits> + One,
+{
     /// Divides by `rhs` at the same precision, rounding down.
     /// `None` on overflow or division by zero.
     ///
@@ -140,7 +244,7 @@ where
         if rhs == Self::zero() {
             None
         } else {
-            self.mul_div_floor(Self::one(), rhs)
+            self.div_scaled(rhs, MulDiv::mul_div_floor)
         }
     }
 
@@ -160,7 +264,7 @@ where
         if rhs == Self::zero() {
             None
         } else {
-            self.mul_div_ceil(Self::one(), rhs)
+            self.div_scaled(rhs, MulDiv::mul_div_ceil)
         }
     }
 
@@ -173,7 +277,7 @@ where
     /// assert_eq!(a.mul_floor(a), Some(UFix64::<N3>::new(1_002u64)));
     /// ```
     pub fn mul_floor(self, rhs: Self) -> Option<Self> {
-        self.mul_div_floor(rhs, Self::one())
+        self.mul_scaled(rhs, MulDiv::mul_div_floor)
     }
 
     /// Multiplies by `rhs` at the same precision, rounding up.
@@ -185,16 +289,289 @@ where
     /// assert_eq!(a.mul_ceil(a), Some(UFix64::<N3>::new(1_003u64)));
     /// ```
     pub fn mul_ceil(self, rhs: Self) -> Option<Self> {
-        self.mul_div_ceil(rhs, Self::one())
+        self.mul_scaled(rhs, MulDiv::mul_div_ceil)
+    }
+
+    /// Multiplies the bits with `mul_div`, then rescales by `SCALE`.
+    fn mul_scaled(self, rhs: Self, mul_div: fn(Bits, Bits, Bits) -> Option<Bits>) -> Option<Self> {
+        let bits = if Self::COARSE {
+            // The exact product is no larger than the scaled one.
+            let product = mul_div(self.bits, rhs.bits, Bits::one())?;
+            mul_div(product, Self::SCALE.bits, Bits::one())?
+        } else {
+            mul_div(self.bits, rhs.bits, Self::SCALE.bits)?
+        };
+        Some(Self::new(bits))
+    }
+
+    /// Divides the bits with `mul_div`, then rescales by `SCALE`. `rhs` must be nonzero.
+    fn div_scaled(self, rhs: Self, mul_div: fn(Bits, Bits, Bits) -> Option<Bits>) -> Option<Self> {
+        let bits = if Self::COARSE {
+            // Nested floors and ceilings compose.
+            let quotient = mul_div(self.bits, Bits::one(), rhs.bits)?;
+            mul_div(quotient, Bits::one(), Self::SCALE.bits)?
+        } else {
+            mul_div(self.bits, Self::SCALE.bits, rhs.bits)?
+        };
+        Some(Self::new(bits))
+    }
+}
+
+impl<Bits, Base, Exp> Fix<Bits, Base, Exp>
+where
+    Self: FixExt,
+    Bits: MulDiv<Output = Bits> + Copy + Zero + PartialOrd,
+{
+    /// Converts to another _Exp_ rounding down, like `checked_convert`, but reports why it fails.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::Overflow`] or [`FixError::Underflow`] if the result does not fit in _Bits_.
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let source = IFix64::<N6>::new(-2_500i64);
+    /// assert_eq!(source.try_convert_floor::<N3>(), Ok(IFix64::<N3>::new(-3i64)));
+    /// assert_eq!(source.try_convert::<N3>(), Ok(IFix64::<N3>::new(-2i64)));
+    /// ```
+    pub fn try_convert_floor<ToExp>(self) -> Result<Fix<Bits, Base, ToExp>, FixError>
+    where
+        Fix<Bits, Base, ToExp>: FixExt,
+    {
+        Fix::<Bits, Base, ToExp>::one().try_mul_div_floor(self, Self::one())
+    }
+
+    /// Converts to another _Exp_ rounding up, like `checked_convert_ceil`, but reports why it
+    /// fails.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::Overflow`] or [`FixError::Underflow`] if the result does not fit in _Bits_.
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let source = UFix64::<N6>::new(5_001u64);
+    /// assert_eq!(source.try_convert_ceil::<N3>(), Ok(UFix64::<N3>::new(6u64)));
+    /// ```
+    pub fn try_convert_ceil<ToExp>(self) -> Result<Fix<Bits, Base, ToExp>, FixError>
+    where
+        Fix<Bits, Base, ToExp>: FixExt,
+    {
+        Fix::<Bits, Base, ToExp>::one().try_mul_div_ceil(self, Self::one())
+    }
+
+    /// Converts to another _Exp_, failing unless the value is exactly representable there.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::PrecisionLoss`] if converting would round, otherwise [`FixError::Overflow`]
+    /// or [`FixError::Underflow`] if the result does not fit in _Bits_.
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let source = UFix64::<N6>::new(5_000u64);
+    /// assert_eq!(source.try_convert_exact::<N3>(), Ok(UFix64::<N3>::new(5u64)));
+    /// let source = UFix64::<N6>::new(5_001u64);
+    /// assert_eq!(source.try_convert_exact::<N3>(), Err(FixError::PrecisionLoss));
+    /// ```
+    pub fn try_convert_exact<ToExp>(self) -> Result<Fix<Bits, Base, ToExp>, FixError>
+    where
+        Fix<Bits, Base, ToExp>: FixExt,
+    {
+        let target_one = Fix::<Bits, Base, ToExp>::one();
+        let floor = target_one.try_mul_div_floor(self, Self::one())?;
+        match target_one.try_mul_div_ceil(self, Self::one()) {
+            Ok(ceil) if ceil == floor => Ok(floor),
+            _ => Err(FixError::PrecisionLoss),
+        }
+    }
+
+    /// Divides by `rhs` at the same precision rounding down, like `div_floor`, but reports why it
+    /// fails.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::DivisionByZero`] if `rhs` is zero, otherwise [`FixError::Overflow`] or
+    /// [`FixError::Underflow`] if the result does not fit in _Bits_.
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let a = UFix64::<N3>::new(10_000u64);
+    /// assert_eq!(a.try_div_floor(UFix64::new(3_000u64)), Ok(UFix64::<N3>::new(3_333u64)));
+    /// assert_eq!(a.try_div_floor(UFix64::zero()), Err(FixError::DivisionByZero));
+    /// ```
+    pub fn try_div_floor(self, rhs: Self) -> Result<Self, FixError> {
+        self.try_mul_div_floor(Self::one(), rhs)
+    }
+
+    /// Divides by `rhs` at the same precision rounding up, like `div_ceil`, but reports why it
+    /// fails.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::DivisionByZero`] if `rhs` is zero, otherwise [`FixError::Overflow`] or
+    /// [`FixError::Underflow`] if the result does not fit in _Bits_.
+    pub fn try_div_ceil(self, rhs: Self) -> Result<Self, FixError> {
+        self.try_mul_div_ceil(Self::one(), rhs)
+    }
+
+    /// Multiplies by `rhs` at the same precision rounding down, like `mul_floor`, but reports
+    /// which way it overflows.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::Overflow`] or [`FixError::Underflow`] if the result does not fit in _Bits_.
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let a = IFix64::<N3>::new(i64::MAX);
+    /// assert_eq!(a.try_mul_floor(IFix64::new(-2_000i64)), Err(FixError::Underflow));
+    /// ```
+    pub fn try_mul_floor(self, rhs: Self) -> Result<Self, FixError> {
+        self.try_mul_div_floor(rhs, Self::one())
+    }
+
+    /// Multiplies by `rhs` at the same precision rounding up, like `mul_ceil`, but reports which
+    /// way it overflows.
+    ///
+    /// # Errors
+    ///
+    /// [`FixError::Overflow`] or [`FixError::Underflow`] if the result does not fit in _Bits_.
+    pub fn try_mul_ceil(self, rhs: Self) -> Result<Self, FixError> {
+        self.try_mul_div_ceil(rhs, Self::one())
+    }
+
+    /// Multiplies by `rhs` at the same precision rounding down, like `mul_floor`, but saturates
+    /// at the bounds of _Bits_ instead of overflowing.
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let a = IFix64::<N3>::new(i64::MAX);
+    /// assert_eq!(a.saturating_mul_floor(IFix64::new(2_000i64)), IFix64::new(i64::MAX));
+    /// assert_eq!(a.saturating_mul_floor(IFix64::new(-2_000i64)), IFix64::new(i64::MIN));
+    /// ```
+    #[must_use]
+    pub fn saturating_mul_floor(self, rhs: Self) -> Self
+    where
+        Bits: Bounded,
+    {
+        match self.try_mul_floor(rhs) {
+            Ok(product) => product,
+            Err(FixError::Underflow) => Self::new(Bits::min_value()),
+            Err(_) => Self::new(Bits::max_value()),
+        }
+    }
+}
+
+macro_rules! impl_wrapping_mul {
+    ($bits:ident) => {
+        impl<Base, Exp> Fix<$bits, Base, Exp>
+        where
+            Self: FixExt,
+        {
+            /// Multiplies by `rhs` at the same precision rounding down, like `mul_floor`, but
+            /// wraps around at the bounds of _Bits_ instead of overflowing.
+            ///
+            /// ```
+            /// use fix::prelude::*;
+            /// let a = UFix64::<N3>::new(u64::MAX);
+            /// assert_eq!(a.wrapping_mul_floor(UFix64::new(2_000)), UFix64::new(u64::MAX - 1));
+            /// ```
+            #[must_use]
+            pub fn wrapping_mul_floor(self, rhs: Self) -> Self {
+                self.overflowing_mul_floor(rhs).0
+            }
+
+            /// Multiplies by `rhs` at the same precision rounding down, returning the wrapped
+            /// product and whether it overflowed.
+            ///
+            /// ```
+            /// use fix::prelude::*;
+            /// let a = IFix64::<N3>::new(-1_500);
+            /// assert_eq!(a.overflowing_mul_floor(a), (IFix64::new(2_250), false));
+            /// let b = IFix64::<N3>::new(i64::MIN);
+            /// assert_eq!(b.overflowing_mul_floor(IFix64::new(-1_000)), (b, true));
+            /// ```
+            pub fn overflowing_mul_floor(self, rhs: Self) -> (Self, bool) {
+                let (floor, overflowed) = self
+                    .bits
+                    .overflowing_mul_div_floor(rhs.bits, Self::one().bits);
+                (Self::new(floor), overflowed)
+            }
+        }
+    };
+}
+
+impl_wrapping_mul!(u8);
+impl_wrapping_mul!(u16);
+impl_wrapping_mul!(u32);
+impl_wrapping_mul!(u64);
+impl_wrapping_mul!(u128);
+impl_wrapping_mul!(usize);
+impl_wrapping_mul!(i8);
+impl_wrapping_mul!(i16);
+impl_wrapping_mul!(i32);
+impl_wrapping_mul!(i64);
+impl_wrapping_mul!(i128);
+impl_wrapping_mul!(isize);
+
+impl<Bits, Base, Exp> Fix<Bits, Base, Exp>
+where
+    Self: FixExt,
+    Bits: MulDivWith<Output = Bits>,
+{
+    /// Converts to another _Exp_ with the given rounding, returning `None` on overflow.
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let source = IFix64::<N6>::new(-2_500i64);
+    /// assert_eq!(source.convert_with::<N3>(Rounding::HalfEven), Some(IFix64::<N3>::new(-2i64)));
+    /// assert_eq!(source.convert_with::<N3>(Rounding::HalfUp), Some(IFix64::<N3>::new(-3i64)));
+    /// ```
+    pub fn convert_with<ToExp>(self, rounding: Rounding) -> Option<Fix<Bits, Base, ToExp>>
+    where
+        Fix<Bits, Base, ToExp>: FixExt,
+    {
+        let target_one = Fix::<Bits, Base, ToExp>::one();
+        target_one.mul_div_with(self, Self::one(), rounding)
+    }
+
+    /// Multiplies by `rhs` at the same precision with the given rounding.
+    /// `None` on overflow.
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let a = UFix64::<N3>::new(1_500u64);
+    /// let b = UFix64::<N3>::new(1_001u64);
+    /// assert_eq!(a.mul_with(b, Rounding::HalfEven), Some(UFix64::<N3>::new(1_502u64)));
+    /// ```
+    pub fn mul_with(self, rhs: Self, rounding: Rounding) -> Option<Self> {
+        self.mul_div_with(rhs, Self::one(), rounding)
+    }
+
+    /// Divides by `rhs` at the same precision with the given rounding.
+    /// `None` on overflow or division by zero.
+    ///
+    /// ```
+    /// use fix::prelude::*;
+    /// let a = IFix64::<N3>::new(-10_000i64);
+    /// let b = IFix64::<N3>::new(3_000i64);
+    /// assert_eq!(a.div_with(b, Rounding::TowardZero), Some(IFix64::<N3>::new(-3_333i64)));
+    /// ```
+    pub fn div_with(self, rhs: Self, rounding: Rounding) -> Option<Self> {
+        self.mul_div_with(Self::one(), rhs, rounding)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::aliases::decimal::{IFix64, UFix64};
-    #[cfg(feature = "typed-floats")]
-    use crate::typenum::N6;
-    use crate::typenum::{N3, N9};
+    use crate::aliases::decimal::{IFix128, IFix64, IFix8, IFixSize, UFix128, UFix64, UFixSize};
+    use crate::aliases::si::{Kilo, Unit};
+    use crate::aliases::{binary, iec};
+    use crate::error::FixError;
+    use crate::rounding::Rounding;
+    use crate::typenum::{N1, N16, N18, N2, N3, N6, N64, N8, N9, P3, U16, Z0};
+    use crate::Fix;
 
     #[test]
     fn to_f64_small_bits_exact() {
@@ -216,6 +593,16 @@ mod tests {
         assert!(((got - expected) / expected).abs() < 1e-15);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn to_f64_matches_to_primitive() {
+        use crate::num_traits::ToPrimitive;
+
+        let x = UFix64::<N9>::new(300_000_000u64);
+        assert_eq!(x.to_f64(), 0.3);
+        assert_eq!(Some(x.to_f64()), ToPrimitive::to_f64(&x));
+    }
+
     #[cfg(feature = "typed-floats")]
     #[test]
     fn to_positive_f64_zero_is_none() {
@@ -268,6 +655,190 @@ mod tests {
         assert_eq!(a.div_ceil(b), Some(IFix64::<N3>::new(-3_333i64)));
     }
 
+    #[test]
+    fn div_with_directed_matches_floor_and_ceil() {
+        let b = IFix64::<N3>::new(3_000i64);
+        for bits in -10_000i64..=10_000 {
+            let a = IFix64::<N3>::new(bits);
+            assert_eq!(a.div_with(b, Rounding::Floor), a.div_floor(b));
+            assert_eq!(a.div_with(b, Rounding::Ceil), a.div_ceil(b));
+        }
+    }
+
+    #[test]
+    fn div_with_negative_modes() {
+        let a = IFix64::<N3>::new(-10_000i64);
+        let b = IFix64::<N3>::new(3_000i64);
+        let div = |rounding| a.div_with(b, rounding).map(|x| x.bits);
+        assert_eq!(div(Rounding::Floor), Some(-3_334));
+        assert_eq!(div(Rounding::Ceil), Some(-3_333));
+        assert_eq!(div(Rounding::TowardZero), Some(-3_333));
+        assert_eq!(div(Rounding::AwayFromZero), Some(-3_334));
+        assert_eq!(div(Rounding::HalfUp), Some(-3_333));
+    }
+
+    #[test]
+    fn div_with_by_zero_is_none() {
+        let a = UFix64::<N3>::new(10_000u64);
+        assert_eq!(a.div_with(UFix64::<N3>::zero(), Rounding::HalfEven), None);
+    }
+
+    #[test]
+    fn mul_with_ties() {
+        let a = IFix64::<N3>::new(-1_500i64);
+        let b = IFix64::<N3>::new(1_001i64);
+        let mul = |rounding| a.mul_with(b, rounding).map(|x| x.bits);
+        assert_eq!(mul(Rounding::HalfUp), Some(-1_502));
+        assert_eq!(mul(Rounding::HalfDown), Some(-1_501));
+        assert_eq!(mul(Rounding::HalfEven), Some(-1_502));
+        assert_eq!(mul(Rounding::Floor), Some(-1_502));
+        assert_eq!(mul(Rounding::Ceil), Some(-1_501));
+    }
+
+    #[test]
+    fn mul_with_overflow_is_none() {
+        let a = UFix64::<N3>::new(u64::MAX);
+        assert_eq!(a.mul_with(a, Rounding::TowardZero), None);
+    }
+
+    #[test]
+    fn convert_with_modes() {
+        let source = UFix64::<N6>::new(2_500u64);
+        let convert = |rounding| source.convert_with::<N3>(rounding).map(|x| x.bits);
+        assert_eq!(convert(Rounding::HalfEven), Some(2));
+        assert_eq!(convert(Rounding::HalfUp), Some(3));
+        assert_eq!(convert(Rounding::Floor), Some(2));
+        assert_eq!(convert(Rounding::Ceil), Some(3));
+        assert_eq!(
+            UFix64::<N3>::new(5u64).convert_with::<N6>(Rounding::Floor),
+            Some(UFix64::<N6>::new(5_000u64))
+        );
+        assert_eq!(
+            UFix64::<N3>::new(u64::MAX).convert_with::<N9>(Rounding::Floor),
+            None
+        );
+    }
+
+    #[test]
+    fn with_rounding_at_all_widths() {
+        let a = IFix128::<N18>::new(-1_500_000_000_000_000_007);
+        let half = IFix128::<N18>::new(500_000_000_000_000_000);
+        assert_eq!(
+            a.mul_with(half, Rounding::HalfEven),
+            Some(IFix128::new(-750_000_000_000_000_004))
+        );
+        assert_eq!(
+            a.mul_with(half, Rounding::HalfDown),
+            Some(IFix128::new(-750_000_000_000_000_003))
+        );
+        assert_eq!(
+            IFix128::<N18>::new(i128::MAX).div_with(IFix128::new(i128::MAX), Rounding::HalfEven),
+            Some(IFix128::new(1_000_000_000_000_000_000))
+        );
+        assert_eq!(
+            IFix128::<N18>::new(-250_000_000_000_000_000).convert_with::<N1>(Rounding::HalfEven),
+            Some(IFix128::new(-2))
+        );
+        let size = UFixSize::<N3>::new(2_500);
+        assert_eq!(
+            size.convert_with::<Z0>(Rounding::HalfEven),
+            Some(UFixSize::new(2))
+        );
+        assert_eq!(
+            IFixSize::<N3>::new(-2_500).convert_with::<Z0>(Rounding::HalfDown),
+            Some(IFixSize::new(-2))
+        );
+    }
+
+    #[test]
+    fn one_at_zero_exp() {
+        assert_eq!(Unit::<u64>::one(), Unit::new(1));
+        assert_eq!(iec::Unit::<i8>::one(), iec::Unit::new(1));
+        assert_eq!(UFix128::<Z0>::one().bits, 1);
+    }
+
+    #[test]
+    fn one_binary() {
+        assert_eq!(binary::UFix64::<N8>::one().bits, 256);
+        assert_eq!(binary::IFix8::<N6>::one().bits, 64);
+        assert_eq!(binary::UFix128::<N64>::one().bits, 1 << 64);
+    }
+
+    #[test]
+    fn one_other_base() {
+        assert_eq!(Fix::<u32, U16, N2>::one().bits, 256);
+        assert_eq!(IFix8::<N2>::one().bits, 100);
+    }
+
+    #[test]
+    fn checked_convert_binary() {
+        let x = binary::UFix64::<N8>::new(640u64);
+        assert_eq!(
+            x.checked_convert::<Z0>(),
+            Some(binary::UFix64::<Z0>::new(2u64))
+        );
+        assert_eq!(
+            x.checked_convert::<N16>(),
+            Some(binary::UFix64::<N16>::new(163_840u64))
+        );
+        assert_eq!(
+            x.checked_convert_ceil::<Z0>(),
+            Some(binary::UFix64::<Z0>::new(3u64))
+        );
+    }
+
+    #[test]
+    fn mul_and_div_binary() {
+        let a = binary::IFix64::<N8>::new(-384i64);
+        let b = binary::IFix64::<N8>::new(640i64);
+        assert_eq!(a.mul_floor(b), Some(binary::IFix64::<N8>::new(-960i64)));
+        assert_eq!(a.div_floor(b), Some(binary::IFix64::<N8>::new(-154i64)));
+        assert_eq!(a.div_ceil(b), Some(binary::IFix64::<N8>::new(-153i64)));
+    }
+
+    #[test]
+    fn mul_and_div_unit() {
+        let a = Unit::new(7u32);
+        let b = Unit::new(2u32);
+        assert_eq!(a.mul_floor(b), Some(Unit::new(14u32)));
+        assert_eq!(a.div_floor(b), Some(Unit::new(3u32)));
+        assert_eq!(a.div_with(b, Rounding::HalfEven), Some(Unit::new(4u32)));
+    }
+
+    #[test]
+    fn mul_and_div_kilo() {
+        assert_eq!(
+            Kilo::new(3u64).checked_convert::<Z0>(),
+            Some(Unit::new(3_000u64))
+        );
+        assert_eq!(
+            Unit::new(2_500u64).checked_convert::<P3>(),
+            Some(Kilo::new(2u64))
+        );
+        assert_eq!(
+            Unit::new(2_500u64).checked_convert_ceil::<P3>(),
+            Some(Kilo::new(3u64))
+        );
+        assert_eq!(Kilo::new(3u64).checked_convert::<N18>(), None);
+        assert_eq!(
+            Kilo::new(0u64).checked_convert::<N18>(),
+            Some(UFix64::new(0))
+        );
+        let tiny = IFix64::<N18>::new(-1i64);
+        assert_eq!(tiny.checked_convert::<P3>(), Some(Kilo::new(-1i64)));
+        assert_eq!(tiny.checked_convert_ceil::<P3>(), Some(Kilo::new(0i64)));
+
+        let (a, b) = (Kilo::new(-7i64), Kilo::new(2i64));
+        assert_eq!(a.mul_floor(b), Some(Kilo::new(-14_000i64)));
+        assert_eq!(a.div_floor(b), Some(Kilo::new(-1i64)));
+        assert_eq!(a.div_ceil(b), Some(Kilo::new(0i64)));
+        let c = Kilo::new(7_000i64);
+        assert_eq!(c.div_floor(b), Some(Kilo::new(3i64)));
+        assert_eq!(c.div_ceil(b), Some(Kilo::new(4i64)));
+        assert_eq!(c.div_floor(Kilo::new(0i64)), None);
+        assert_eq!(Kilo::new(u64::MAX).mul_floor(Kilo::new(1u64)), None);
+    }
+
     #[test]
     fn mul_floor_rounds_down() {
         let a = UFix64::<N3>::new(1_001u64);
@@ -287,6 +858,63 @@ mod tests {
         assert_eq!(a.mul_floor(a), None);
     }
 
+    #[test]
+    fn wrapping_mul_floor_matches_mul_floor() {
+        for a in -30i8..=30 {
+            for b in [-30i8, -7, -1, 0, 1, 9, 30] {
+                let (a, b) = (IFix8::<N1>::new(a), IFix8::<N1>::new(b));
+                let (product, overflowed) = a.overflowing_mul_floor(b);
+                assert_eq!(a.mul_floor(b), (!overflowed).then_some(product));
+                assert_eq!(a.wrapping_mul_floor(b), product);
+            }
+        }
+    }
+
+    #[test]
+    fn wrapping_mul_floor_wraps() {
+        let a = IFix8::<N1>::new(-128);
+        assert_eq!(a.overflowing_mul_floor(a), (IFix8::new(102), true));
+        let b = UFix64::<N3>::new(u64::MAX);
+        assert!(b.overflowing_mul_floor(b).1);
+        assert_eq!(
+            Unit::new(u64::MAX).wrapping_mul_floor(Unit::new(u64::MAX)),
+            Unit::new(1u64)
+        );
+
+        let c = UFix128::<N1>::new(1 << 127);
+        assert_eq!(
+            c.overflowing_mul_floor(UFix128::new(20)),
+            (UFix128::new(0), true)
+        );
+        assert_eq!(c.overflowing_mul_floor(UFix128::new(10)), (c, false));
+        assert_eq!(
+            Unit::new(u128::MAX).wrapping_mul_floor(Unit::new(u128::MAX)),
+            Unit::new(1u128)
+        );
+        let d = IFix128::<N1>::new(i128::MIN);
+        assert_eq!(d.overflowing_mul_floor(IFix128::new(10)), (d, false));
+        assert_eq!(d.overflowing_mul_floor(IFix128::new(-10)), (d, true));
+        assert_eq!(
+            d.overflowing_mul_floor(IFix128::new(15)),
+            (IFix128::new(1 << 126), true)
+        );
+        let max = IFix128::<N1>::new(i128::MAX);
+        assert_eq!(max.overflowing_mul_floor(IFix128::new(10)), (max, false));
+        assert_eq!(
+            IFix128::<N1>::new(-3).overflowing_mul_floor(IFix128::new(5)),
+            (IFix128::new(-2), false)
+        );
+        assert_eq!(
+            IFixSize::<N1>::new(-3).overflowing_mul_floor(IFixSize::new(5)),
+            (IFixSize::new(-2), false)
+        );
+        assert!(
+            UFixSize::<N1>::new(usize::MAX)
+                .overflowing_mul_floor(UFixSize::new(20))
+                .1
+        );
+    }
+
     #[test]
     fn mul_ceil_rounds_up() {
         let a = UFix64::<N3>::new(1_001u64);
@@ -305,4 +933,51 @@ mod tests {
         let a = UFix64::<N3>::new(u64::MAX);
         assert_eq!(a.mul_ceil(a), None);
     }
+
+    #[test]
+    fn try_div_reports_division_by_zero() {
+        let a = IFix64::<N3>::new(10_000i64);
+        assert_eq!(
+            a.try_div_floor(IFix64::zero()),
+            Err(FixError::DivisionByZero)
+        );
+        assert_eq!(
+            a.try_div_ceil(IFix64::zero()),
+            Err(FixError::DivisionByZero)
+        );
+        assert_eq!(
+            a.try_div_ceil(IFix64::new(-3_000i64)),
+            Ok(IFix64::new(-3_333i64))
+        );
+    }
+
+    #[test]
+    fn try_mul_reports_direction() {
+        let a = IFix64::<N3>::new(i64::MAX);
+        assert_eq!(a.try_mul_ceil(a), Err(FixError::Overflow));
+        assert_eq!(
+            a.try_mul_ceil(IFix64::new(-i64::MAX)),
+            Err(FixError::Underflow)
+        );
+        assert_eq!(a.try_mul_floor(IFix64::one()), Ok(a));
+    }
+
+    #[test]
+    fn try_convert_exact_floor_and_ceil() {
+        let source = IFix64::<N6>::new(-2_500i64);
+        assert_eq!(source.try_convert_floor::<N3>(), Ok(IFix64::new(-3i64)));
+        assert_eq!(
+            source.try_convert_exact::<N3>(),
+            Err(FixError::PrecisionLoss)
+        );
+        assert_eq!(source.try_convert_ceil::<N3>(), Ok(IFix64::new(-2i64)));
+        assert_eq!(
+            source.try_convert_exact::<N9>(),
+            Ok(IFix64::new(-2_500_000i64))
+        );
+        assert_eq!(
+            IFix64::<N3>::new(i64::MIN).try_convert_exact::<N6>(),
+            Err(FixError::Underflow)
+        );
+    }
 }